@@ -352,7 +352,7 @@ pub fn parse_with_proc_macros(
     }
 }
 
-fn default_test_proc_macros() -> [(String, ProcMacro); 5] {
+fn default_test_proc_macros() -> [(String, ProcMacro); 6] {
     [
         (
             r#"
@@ -384,6 +384,20 @@ pub fn derive_identity(item: TokenStream) -> TokenStream {
         ),
         (
             r#"
+#[proc_macro_derive(Serialize, attributes(serde))]
+pub fn serialize(item: TokenStream) -> TokenStream {
+    item
+}
+"#
+            .into(),
+            ProcMacro {
+                name: "Serialize".into(),
+                kind: crate::ProcMacroKind::CustomDerive,
+                expander: sync::Arc::new(IdentityProcMacroExpander),
+            },
+        ),
+        (
+            r#"
 #[proc_macro_attribute]
 pub fn input_replace(attr: TokenStream, _item: TokenStream) -> TokenStream {
     attr
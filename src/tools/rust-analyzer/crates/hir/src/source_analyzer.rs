@@ -668,7 +668,7 @@ pub(crate) fn resolve_path(
                     {
                         variant.syntax().ancestors().nth(2).and_then(ast::Adt::cast)
                     } else {
-                        None
+                        attr.syntax().parent().and_then(ast::Adt::cast)
                     };
                     if let Some(adt) = adt {
                         let ast_id = db.ast_id_map(self.file_id).ast_id(&adt);
@@ -8,6 +8,7 @@
 pub(crate) mod flyimport;
 pub(crate) mod fn_param;
 pub(crate) mod format_string;
+pub(crate) mod fragment_specifier;
 pub(crate) mod item_list;
 pub(crate) mod keyword;
 pub(crate) mod lifetime;
@@ -35,9 +36,11 @@
     },
     item::Builder,
     render::{
-        const_::render_const,
-        function::{render_fn, render_method},
-        literal::{render_struct_literal, render_variant_lit},
+        const_::{render_const, render_qualified_const},
+        function::{render_fallible_fn, render_fn, render_method, render_method_through_trait},
+        literal::{
+            render_struct_literal, render_struct_literal_with_all_fields, render_variant_lit,
+        },
         macro_::render_macro,
         pattern::{render_struct_pat, render_variant_pat},
         render_field, render_path_resolution, render_pattern_resolution, render_tuple_field,
@@ -45,7 +48,7 @@
         union_literal::render_union_literal,
         RenderContext,
     },
-    CompletionContext, CompletionItem, CompletionItemKind,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionRelevance,
 };
 
 /// Represents an in-progress set of completions being built.
@@ -80,6 +83,9 @@ fn add_opt(&mut self, item: Option<CompletionItem>) {
     }
 
     pub(crate) fn add_keyword(&mut self, ctx: &CompletionContext<'_>, keyword: &'static str) {
+        if !ctx.config.enable_keyword_completions {
+            return;
+        }
         let item = CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), keyword);
         item.add_to(self, ctx.db);
     }
@@ -119,6 +125,9 @@ pub(crate) fn add_keyword_snippet_expr(
         kw: &str,
         snippet: &str,
     ) {
+        if !ctx.config.enable_keyword_completions {
+            return;
+        }
         let mut item = CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), kw);
 
         match ctx.config.snippet_cap {
@@ -144,6 +153,9 @@ pub(crate) fn add_keyword_snippet(
         kw: &str,
         snippet: &str,
     ) {
+        if !ctx.config.enable_keyword_completions {
+            return;
+        }
         let mut item = CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), kw);
 
         match ctx.config.snippet_cap {
@@ -174,25 +186,29 @@ pub(crate) fn add_path_resolution(
         resolution: hir::ScopeDef,
         doc_aliases: Vec<syntax::SmolStr>,
     ) {
-        if !ctx.check_stability(resolution.attrs(ctx.db).as_deref()) {
-            return;
+        if let Some(item) =
+            build_path_resolution(ctx, path_ctx, local_name, resolution, doc_aliases)
+        {
+            self.add(item.build(ctx.db));
+        }
+    }
+
+    /// Adds a trait completed in bound position whose methods are already called on values of
+    /// the bounded type parameter.
+    pub(crate) fn add_implied_bound(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        path_ctx: &PathCompletionCtx,
+        local_name: hir::Name,
+        resolution: hir::ScopeDef,
+        doc_aliases: Vec<syntax::SmolStr>,
+    ) {
+        if let Some(mut item) =
+            build_path_resolution(ctx, path_ctx, local_name, resolution, doc_aliases)
+        {
+            item.with_relevance(|r| CompletionRelevance { is_implied_bound: true, ..r });
+            self.add(item.build(ctx.db));
         }
-        let is_private_editable = match ctx.def_is_visible(&resolution) {
-            Visible::Yes => false,
-            Visible::Editable => true,
-            Visible::No => return,
-        };
-        self.add(
-            render_path_resolution(
-                RenderContext::new(ctx)
-                    .private_editable(is_private_editable)
-                    .doc_aliases(doc_aliases),
-                path_ctx,
-                local_name,
-                resolution,
-            )
-            .build(ctx.db),
-        );
     }
 
     pub(crate) fn add_pattern_resolution(
@@ -261,6 +277,18 @@ pub(crate) fn add_macro(
         path_ctx: &PathCompletionCtx,
         mac: hir::Macro,
         local_name: hir::Name,
+    ) {
+        self.add_macro_with_relevance(ctx, path_ctx, mac, local_name, |r| r);
+    }
+
+    /// Like [`Self::add_macro`], with the relevance of the item adjusted by `relevance`.
+    pub(crate) fn add_macro_with_relevance(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        path_ctx: &PathCompletionCtx,
+        mac: hir::Macro,
+        local_name: hir::Name,
+        relevance: impl FnOnce(CompletionRelevance) -> CompletionRelevance,
     ) {
         if !ctx.check_stability(Some(&mac.attrs(ctx.db))) {
             return;
@@ -270,15 +298,14 @@ pub(crate) fn add_macro(
             Visible::Editable => true,
             Visible::No => return,
         };
-        self.add(
-            render_macro(
-                RenderContext::new(ctx).private_editable(is_private_editable),
-                path_ctx,
-                local_name,
-                mac,
-            )
-            .build(ctx.db),
+        let mut item = render_macro(
+            RenderContext::new(ctx).private_editable(is_private_editable),
+            path_ctx,
+            local_name,
+            mac,
         );
+        item.with_relevance(relevance);
+        self.add(item.build(ctx.db));
     }
 
     pub(crate) fn add_function(
@@ -310,6 +337,40 @@ pub(crate) fn add_function(
         );
     }
 
+    pub(crate) fn add_fallible_function(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        path_ctx: &PathCompletionCtx,
+        func: hir::Function,
+        local_name: Option<hir::Name>,
+        innermost_ret_ty: &hir::Type,
+    ) {
+        if !ctx.check_stability(Some(&func.attrs(ctx.db))) {
+            return;
+        }
+        let is_private_editable = match ctx.is_visible(&func) {
+            Visible::Yes => false,
+            Visible::Editable => true,
+            Visible::No => return,
+        };
+        let doc_aliases = ctx.doc_aliases(&func);
+        for map_err in [false, true] {
+            self.add_opt(
+                render_fallible_fn(
+                    RenderContext::new(ctx)
+                        .private_editable(is_private_editable)
+                        .doc_aliases(doc_aliases.clone()),
+                    path_ctx,
+                    local_name.clone(),
+                    func,
+                    innermost_ret_ty,
+                    map_err,
+                )
+                .map(|builder| builder.build(ctx.db)),
+            );
+        }
+    }
+
     pub(crate) fn add_method(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -372,6 +433,29 @@ pub(crate) fn add_method_with_import(
         );
     }
 
+    /// Adds a method that can't be told apart from a same-named method of another trait on its
+    /// receiver, calling it through its trait instead, i.e. `Trait::method(receiver)`.
+    pub(crate) fn add_method_through_trait(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        dot_access: &DotAccess,
+        func: hir::Function,
+    ) {
+        if !ctx.check_stability(Some(&func.attrs(ctx.db))) {
+            return;
+        }
+        let is_private_editable = match ctx.is_visible(&func) {
+            Visible::Yes => false,
+            Visible::Editable => true,
+            Visible::No => return,
+        };
+        let render_ctx = RenderContext::new(ctx).private_editable(is_private_editable);
+        match render_method_through_trait(render_ctx.clone(), dot_access, func) {
+            Some(item) => self.add(item.build(ctx.db)),
+            None => self.add(render_method(render_ctx, dot_access, None, None, func).build(ctx.db)),
+        }
+    }
+
     pub(crate) fn add_const(&mut self, ctx: &CompletionContext<'_>, konst: hir::Const) {
         if !ctx.check_stability(Some(&konst.attrs(ctx.db))) {
             return;
@@ -387,6 +471,27 @@ pub(crate) fn add_const(&mut self, ctx: &CompletionContext<'_>, konst: hir::Cons
         ));
     }
 
+    pub(crate) fn add_qualified_const(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        konst: hir::Const,
+        qualifier: &hir::ModPath,
+    ) {
+        if !ctx.check_stability(Some(&konst.attrs(ctx.db))) {
+            return;
+        }
+        let is_private_editable = match ctx.is_visible(&konst) {
+            Visible::Yes => false,
+            Visible::Editable => true,
+            Visible::No => return,
+        };
+        self.add_opt(render_qualified_const(
+            RenderContext::new(ctx).private_editable(is_private_editable),
+            konst,
+            qualifier,
+        ));
+    }
+
     pub(crate) fn add_type_alias(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -495,9 +600,22 @@ pub(crate) fn add_struct_literal(
         if !ctx.check_stability(Some(&strukt.attrs(ctx.db))) {
             return;
         }
-        if let Some(builder) =
-            render_struct_literal(RenderContext::new(ctx), path_ctx, strukt, path, local_name)
-        {
+        if let Some(builder) = render_struct_literal(
+            RenderContext::new(ctx),
+            path_ctx,
+            strukt,
+            path.clone(),
+            local_name.clone(),
+        ) {
+            self.add(builder.build(ctx.db));
+        }
+        if let Some(builder) = render_struct_literal_with_all_fields(
+            RenderContext::new(ctx),
+            path_ctx,
+            strukt,
+            path,
+            local_name,
+        ) {
             self.add(builder.build(ctx.db));
         }
     }
@@ -595,6 +713,30 @@ pub(crate) fn add_struct_pat(
     }
 }
 
+/// Renders a path resolution, unless it is unstable or not visible from the completion site.
+fn build_path_resolution(
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    local_name: hir::Name,
+    resolution: hir::ScopeDef,
+    doc_aliases: Vec<syntax::SmolStr>,
+) -> Option<Builder> {
+    if !ctx.check_stability(resolution.attrs(ctx.db).as_deref()) {
+        return None;
+    }
+    let is_private_editable = match ctx.def_is_visible(&resolution) {
+        Visible::Yes => false,
+        Visible::Editable => true,
+        Visible::No => return None,
+    };
+    Some(render_path_resolution(
+        RenderContext::new(ctx).private_editable(is_private_editable).doc_aliases(doc_aliases),
+        path_ctx,
+        local_name,
+        resolution,
+    ))
+}
+
 /// Calls the callback for each variant of the provided enum with the path to the variant.
 /// Skips variants that are visible with single segment paths.
 fn enum_variants_with_paths(
@@ -686,6 +828,7 @@ pub(super) fn complete_name_ref(
     match kind {
         NameRefKind::Path(path_ctx) => {
             flyimport::import_on_the_fly_path(acc, ctx, path_ctx);
+            flyimport::import_qualifier_and_shorten(acc, ctx, path_ctx);
 
             match &path_ctx.kind {
                 PathKind::Expr { expr_ctx } => {
@@ -704,11 +847,20 @@ pub(super) fn complete_name_ref(
                         }
                         TypeLocation::TypeAscription(ascription) => {
                             r#type::complete_ascribed_type(acc, ctx, path_ctx, ascription);
+                            snippet::complete_test_result_return_snippet(
+                                acc, ctx, path_ctx, ascription,
+                            );
+                        }
+                        TypeLocation::CastTarget => {
+                            r#type::complete_inferred_cast_target(acc, ctx, path_ctx);
+                        }
+                        TypeLocation::TypeBound => {
+                            snippet::complete_impl_trait_return_snippet(acc, ctx, path_ctx);
+                            snippet::complete_impl_trait_param_snippet(acc, ctx, path_ctx);
                         }
                         TypeLocation::GenericArg { .. }
                         | TypeLocation::AssocConstEq
                         | TypeLocation::AssocTypeEq
-                        | TypeLocation::TypeBound
                         | TypeLocation::ImplTarget
                         | TypeLocation::ImplTrait
                         | TypeLocation::Other => (),
@@ -766,4 +918,5 @@ fn complete_patterns(
     fn_param::complete_fn_param(acc, ctx, pattern_ctx);
     pattern::complete_pattern(acc, ctx, pattern_ctx);
     record::complete_record_pattern_fields(acc, ctx, pattern_ctx);
+    snippet::complete_let_else_snippet(acc, ctx, pattern_ctx);
 }
@@ -24,6 +24,7 @@
 
 mod cfg;
 mod derive;
+mod derive_helper;
 mod lint;
 mod repr;
 
@@ -48,9 +49,14 @@ pub(crate) fn complete_known_attribute_input(
 
     match path.text().as_str() {
         "repr" => repr::complete_repr(acc, ctx, tt),
-        "feature" => {
-            lint::complete_lint(acc, ctx, colon_prefix, &parse_tt_as_comma_sep_paths(tt)?, FEATURES)
-        }
+        "feature" => lint::complete_lint(
+            acc,
+            ctx,
+            colon_prefix,
+            &parse_tt_as_comma_sep_paths(tt)?,
+            FEATURES,
+            None,
+        ),
         "allow" | "warn" | "deny" | "forbid" => {
             let existing_lints = parse_tt_as_comma_sep_paths(tt)?;
 
@@ -63,10 +69,14 @@ pub(crate) fn complete_known_attribute_input(
                 .cloned()
                 .collect();
 
-            lint::complete_lint(acc, ctx, colon_prefix, &existing_lints, &lints);
+            let boosted_lint = (path.text() == "allow" && lint::annotates_unused_private_item(ctx))
+                .then_some("dead_code");
+            lint::complete_lint(acc, ctx, colon_prefix, &existing_lints, &lints, boosted_lint);
         }
         "cfg" => cfg::complete_cfg(acc, ctx),
-        _ => (),
+        _ => {
+            derive_helper::complete_derive_helper(acc, ctx, tt);
+        }
     }
     Some(())
 }
@@ -182,11 +192,11 @@ const fn attr(
 macro_rules! attrs {
     // attributes applicable to all items
     [@ { item $($tt:tt)* } {$($acc:tt)*}] => {
-        attrs!(@ { $($tt)* } { $($acc)*, "deprecated", "doc", "dochidden", "docalias", "must_use", "no_mangle" })
+        attrs!(@ { $($tt)* } { $($acc)*, "deprecated", "doc", "dochidden", "docalias", "must_use", "must_use_msg", "no_mangle" })
     };
     // attributes applicable to all adts
     [@ { adt $($tt:tt)* } {$($acc:tt)*}] => {
-        attrs!(@ { $($tt)* } { $($acc)*, "derive", "repr" })
+        attrs!(@ { $($tt)* } { $($acc)*, "cfg_attr_test_derive", "derive", "repr" })
     };
     // attributes applicable to all linkable things aka functions/statics
     [@ { linkable $($tt:tt)* } {$($acc:tt)*}] => {
@@ -238,13 +248,13 @@ macro_rules! attrs {
             FN,
             attrs!(
                 item, linkable,
-                "cold", "ignore", "inline", "must_use", "panic_handler", "proc_macro",
+                "cold", "ignore", "inline", "panic_handler", "proc_macro",
                 "proc_macro_derive", "proc_macro_attribute", "should_panic", "target_feature",
                 "test", "track_caller"
             ),
         ),
         (STATIC, attrs!(item, linkable, "global_allocator", "used")),
-        (TRAIT, attrs!(item, "must_use")),
+        (TRAIT, attrs!(item)),
         (IMPL, attrs!(item, "automatically_derived")),
         (ASSOC_ITEM_LIST, attrs!(item)),
         (EXTERN_BLOCK, attrs!(item, "link")),
@@ -279,6 +289,11 @@ macro_rules! attrs {
     attr("automatically_derived", None, None),
     attr("cfg(…)", Some("cfg"), Some("cfg(${0:predicate})")),
     attr("cfg_attr(…)", Some("cfg_attr"), Some("cfg_attr(${1:predicate}, ${0:attr})")),
+    attr(
+        "cfg_attr(test, derive(…))",
+        Some("cfg_attr_test_derive"),
+        Some("cfg_attr(test, derive(${0:Debug}))"),
+    ),
     attr("cold", None, None),
     attr(r#"crate_name = """#, Some("crate_name"), Some(r#"crate_name = "${0:crate_name}""#))
         .prefer_inner(),
@@ -308,6 +323,7 @@ macro_rules! attrs {
     attr("macro_export", None, None),
     attr("macro_use", None, None),
     attr(r#"must_use"#, Some("must_use"), Some(r#"must_use"#)),
+    attr(r#"must_use = "…""#, Some("must_use_msg"), Some(r#"must_use = "$0""#)),
     attr("no_implicit_prelude", None, None).prefer_inner(),
     attr("no_link", None, None).prefer_inner(),
     attr("no_main", None, None).prefer_inner(),
@@ -2,11 +2,11 @@
 use hir::ScopeDef;
 use ide_db::{documentation::HasDocs, SymbolKind};
 use itertools::Itertools;
-use syntax::SmolStr;
+use syntax::{ast, AstNode, SmolStr};
 
 use crate::{
     context::{CompletionContext, ExistingDerives, PathCompletionCtx, Qualified},
-    item::CompletionItem,
+    item::{CompletionItem, CompletionItemKind, CompletionRelevance},
     Completions,
 };
 
@@ -17,6 +17,7 @@ pub(crate) fn complete_derive_path(
     existing_derives: &ExistingDerives,
 ) {
     let core = ctx.famous_defs().core();
+    let copy_eligible = fields_are_copy(ctx);
 
     match qualified {
         Qualified::With {
@@ -43,6 +44,8 @@ pub(crate) fn complete_derive_path(
         Qualified::Absolute => acc.add_crate_roots(ctx, path_ctx),
         // only show modules in a fresh UseTree
         Qualified::No => {
+            let mut available_core_derives = Vec::new();
+            let mut offered_labels = Vec::new();
             ctx.process_all_names(&mut |name, def, doc_aliases| {
                 let mac = match def {
                     ScopeDef::ModuleDef(hir::ModuleDef::Macro(mac))
@@ -63,9 +66,26 @@ pub(crate) fn complete_derive_path(
                 };
 
                 let name_ = name.to_smol_str();
+                available_core_derives.push(name_.clone());
                 let find = DEFAULT_DERIVE_DEPENDENCIES
                     .iter()
                     .find(|derive_completion| derive_completion.label == name_);
+                let follows_existing_derive =
+                    DERIVE_FOLLOW_UPS.iter().any(|&(follow_up, derive)| {
+                        follow_up == name_
+                            && existing_derives
+                                .iter()
+                                .any(|it| it.name(ctx.db).to_smol_str() == derive)
+                    });
+                let popularity_rank = if follows_existing_derive {
+                    Some(0)
+                } else {
+                    DERIVE_POPULARITY
+                        .iter()
+                        .position(|&derive| derive == name_)
+                        .and_then(|rank| u8::try_from(rank).ok())
+                };
+                let is_suggested_by_context = copy_eligible && name_ == "Copy";
 
                 match find {
                     Some(derive_completion) => {
@@ -79,28 +99,103 @@ pub(crate) fn complete_derive_path(
                             },
                         ));
                         let lookup = components.join(", ");
-                        let label = Itertools::intersperse(components.into_iter().rev(), ", ");
+                        let label = SmolStr::from_iter(Itertools::intersperse(
+                            components.into_iter().rev(),
+                            ", ",
+                        ));
+                        offered_labels.push(label.clone());
 
-                        let mut item = CompletionItem::new(
-                            SymbolKind::Derive,
-                            ctx.source_range(),
-                            SmolStr::from_iter(label),
-                        );
+                        let mut item =
+                            CompletionItem::new(SymbolKind::Derive, ctx.source_range(), label);
                         if let Some(docs) = mac.docs(ctx.db) {
                             item.documentation(docs);
                         }
                         item.lookup_by(lookup);
+                        item.set_relevance(CompletionRelevance {
+                            popularity_rank,
+                            is_suggested_by_context,
+                            ..CompletionRelevance::default()
+                        });
                         item.add_to(acc, ctx.db);
                     }
-                    None => acc.add_macro(ctx, path_ctx, mac, name),
+                    None => acc.add_macro_with_relevance(ctx, path_ctx, mac, name, |r| {
+                        CompletionRelevance { popularity_rank, is_suggested_by_context, ..r }
+                    }),
                 }
             });
+            if ctx.config.enable_derive_groups {
+                add_derive_groups(
+                    acc,
+                    ctx,
+                    existing_derives,
+                    &available_core_derives,
+                    &offered_labels,
+                    copy_eligible,
+                );
+            }
             acc.add_nameref_keywords_with_colon(ctx);
         }
         Qualified::TypeAnchor { .. } | Qualified::With { .. } => {}
     }
 }
 
+/// Offers the groups of [`DERIVE_GROUPS`] as single completions inserting the derives of the group
+/// that aren't present yet, unless a derive with its dependencies already inserts the same.
+fn add_derive_groups(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    existing_derives: &ExistingDerives,
+    available: &[SmolStr],
+    offered_labels: &[SmolStr],
+    copy_eligible: bool,
+) {
+    for &group in DERIVE_GROUPS {
+        if group.contains(&"Copy") && !copy_eligible {
+            continue;
+        }
+        let missing: Vec<_> = group
+            .iter()
+            .copied()
+            .filter(|&derive| {
+                !existing_derives.iter().any(|it| it.name(ctx.db).to_smol_str() == derive)
+            })
+            .collect();
+        // A single missing derive is already covered by its own completion.
+        if missing.len() < 2
+            || !missing.iter().all(|&derive| available.iter().any(|it| it == derive))
+        {
+            continue;
+        }
+        let label = missing.join(", ");
+        if offered_labels.iter().any(|it| *it == label) {
+            continue;
+        }
+        let mut item =
+            CompletionItem::new(CompletionItemKind::DeriveGroup, ctx.source_range(), label);
+        item.detail("derive group");
+        item.add_to(acc, ctx.db);
+    }
+}
+
+/// Whether all fields of the type the derive is attached to are `Copy`, so that it can derive
+/// `Copy` as well.
+fn fields_are_copy(ctx: &CompletionContext<'_>) -> bool {
+    (|| {
+        let copy = ctx.famous_defs().core_marker_Copy()?;
+        let attr = ctx.original_token.parent_ancestors().find_map(ast::Attr::cast)?;
+        let adt = ctx.sema.to_def(&ast::Adt::cast(attr.syntax().parent()?)?)?;
+        let fields = match adt {
+            hir::Adt::Struct(it) => it.fields(ctx.db),
+            hir::Adt::Union(it) => it.fields(ctx.db),
+            hir::Adt::Enum(it) => {
+                it.variants(ctx.db).into_iter().flat_map(|it| it.fields(ctx.db)).collect()
+            }
+        };
+        Some(fields.iter().all(|field| field.ty(ctx.db).impls_trait(ctx.db, copy, &[])))
+    })()
+    .unwrap_or(false)
+}
+
 struct DeriveDependencies {
     label: &'static str,
     dependencies: &'static [&'static str],
@@ -114,3 +209,19 @@ struct DeriveDependencies {
     DeriveDependencies { label: "Ord", dependencies: &["PartialOrd", "Eq", "PartialEq"] },
     DeriveDependencies { label: "PartialOrd", dependencies: &["PartialEq"] },
 ];
+
+/// Clusters of standard Rust derives commonly derived together. Each group contains the
+/// dependencies of its derives, so that inserting the missing ones never breaks the compilation.
+const DERIVE_GROUPS: &[&[&str]] = &[
+    &["Debug", "Clone", "PartialEq", "Eq", "Hash"],
+    &["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"],
+    &["Debug", "Clone", "Default"],
+];
+
+/// Standard Rust derives, ordered by how commonly they are used.
+const DERIVE_POPULARITY: &[&str] =
+    &["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash", "Default", "PartialOrd", "Ord"];
+
+/// Standard Rust derives that usually follow another derive once it is present, as
+/// `(follow-up, derive)` pairs.
+const DERIVE_FOLLOW_UPS: &[(&str, &str)] = &[("PartialOrd", "PartialEq"), ("Ord", "Eq")];
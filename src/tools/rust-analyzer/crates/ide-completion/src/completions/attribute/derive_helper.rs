@@ -0,0 +1,131 @@
+//! Completion for the keys of well-known derive helper attributes, like `#[serde(rename = "…")]`.
+
+use ide_db::SymbolKind;
+use syntax::{ast, match_ast, AstNode};
+
+use crate::{context::CompletionContext, item::CompletionItem, Completions};
+
+pub(super) fn complete_derive_helper(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    input: ast::TokenTree,
+) -> Option<()> {
+    // Only complete helpers of derives that are actually applied to the annotated item.
+    let attr = ctx.original_token.parent_ancestors().find_map(ast::Attr::cast)?;
+    let hir::PathResolution::DeriveHelper(helper) = ctx.sema.resolve_path(&attr.path()?)? else {
+        return None;
+    };
+    let helper = helper.name(ctx.db);
+    let (_, keys) = DERIVE_HELPER_KEYS.iter().find(|(name, _)| helper.to_smol_str() == *name)?;
+    let keys = match_ast! {
+        match (attr.syntax().parent()?) {
+            ast::RecordField(_) => keys.field,
+            ast::TupleField(_) => keys.field,
+            ast::Variant(_) => keys.variant,
+            ast::Adt(_) => keys.container,
+            _ => return None,
+        }
+    };
+
+    let existing_keys = super::parse_comma_sep_expr(input)?;
+    let existing_keys: Vec<_> = existing_keys
+        .iter()
+        .filter_map(|expr| match expr {
+            ast::Expr::PathExpr(path) => path.path()?.as_single_name_ref(),
+            ast::Expr::BinExpr(bin) => match bin.lhs()? {
+                ast::Expr::PathExpr(path) => path.path()?.as_single_name_ref(),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    for &HelperKey { name, takes_value } in keys {
+        if existing_keys.iter().any(|it| it.text() == name) {
+            continue;
+        }
+        let label = if takes_value { format!("{name} = \"…\"") } else { name.to_owned() };
+        let mut item = CompletionItem::new(SymbolKind::BuiltinAttr, ctx.source_range(), label);
+        item.lookup_by(name);
+        if let Some(cap) = ctx.config.snippet_cap.filter(|_| takes_value) {
+            item.insert_snippet(cap, format!("{name} = \"$0\""));
+        }
+        item.add_to(acc, ctx.db);
+    }
+    Some(())
+}
+
+struct HelperKey {
+    name: &'static str,
+    takes_value: bool,
+}
+
+const fn key(name: &'static str) -> HelperKey {
+    HelperKey { name, takes_value: false }
+}
+
+const fn key_value(name: &'static str) -> HelperKey {
+    HelperKey { name, takes_value: true }
+}
+
+/// The keys a helper attribute accepts, by the kind of item it annotates.
+struct HelperKeys {
+    container: &'static [HelperKey],
+    variant: &'static [HelperKey],
+    field: &'static [HelperKey],
+}
+
+/// Helper attributes of popular derive macros with the keys they accept.
+#[rustfmt::skip]
+const DERIVE_HELPER_KEYS: &[(&str, HelperKeys)] = &[
+    ("serde", HelperKeys {
+        container: &[
+            key_value("bound"),
+            key_value("content"),
+            key_value("crate"),
+            key("default"),
+            key("deny_unknown_fields"),
+            key_value("expecting"),
+            key_value("from"),
+            key_value("into"),
+            key_value("remote"),
+            key_value("rename"),
+            key_value("rename_all"),
+            key_value("tag"),
+            key("transparent"),
+            key_value("try_from"),
+            key("untagged"),
+        ],
+        variant: &[
+            key_value("alias"),
+            key("borrow"),
+            key_value("bound"),
+            key_value("deserialize_with"),
+            key("other"),
+            key_value("rename"),
+            key_value("rename_all"),
+            key_value("serialize_with"),
+            key("skip"),
+            key("skip_deserializing"),
+            key("skip_serializing"),
+            key("untagged"),
+            key_value("with"),
+        ],
+        field: &[
+            key_value("alias"),
+            key("borrow"),
+            key_value("bound"),
+            key("default"),
+            key_value("deserialize_with"),
+            key("flatten"),
+            key_value("getter"),
+            key_value("rename"),
+            key_value("serialize_with"),
+            key("skip"),
+            key("skip_deserializing"),
+            key("skip_serializing"),
+            key_value("skip_serializing_if"),
+            key_value("with"),
+        ],
+    }),
+];
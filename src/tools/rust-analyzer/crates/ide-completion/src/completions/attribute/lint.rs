@@ -1,8 +1,15 @@
 //! Completion for lints
-use ide_db::{documentation::Documentation, generated::lints::Lint, SymbolKind};
-use syntax::ast;
+use ide_db::{defs::Definition, documentation::Documentation, generated::lints::Lint, SymbolKind};
+use syntax::{
+    ast::{self, HasVisibility},
+    AstNode,
+};
 
-use crate::{context::CompletionContext, item::CompletionItem, Completions};
+use crate::{
+    context::CompletionContext,
+    item::{CompletionItem, CompletionRelevance},
+    Completions,
+};
 
 pub(super) fn complete_lint(
     acc: &mut Completions,
@@ -10,6 +17,7 @@ pub(super) fn complete_lint(
     is_qualified: bool,
     existing_lints: &[ast::Path],
     lints_completions: &[Lint],
+    boosted_lint: Option<&str>,
 ) {
     for &Lint { label, description } in lints_completions {
         let (qual, name) = {
@@ -56,6 +64,48 @@ pub(super) fn complete_lint(
         };
         let mut item = CompletionItem::new(SymbolKind::Attribute, ctx.source_range(), label);
         item.documentation(Documentation::new(description.to_owned()));
+        if qual.is_none() && boosted_lint == Some(name) {
+            item.set_relevance(CompletionRelevance {
+                is_suggested_by_context: true,
+                ..CompletionRelevance::default()
+            });
+        }
         item.add_to(acc, ctx.db)
     }
 }
+
+/// Whether the attribute being completed is attached to a private item that is never used, in
+/// which case `dead_code` is the lint most likely to be allowed.
+pub(super) fn annotates_unused_private_item(ctx: &CompletionContext<'_>) -> bool {
+    (|| {
+        let attr = ctx.original_token.parent_ancestors().find_map(ast::Attr::cast)?;
+        let item = ast::Item::cast(attr.syntax().parent()?)?;
+        let def = match item {
+            ast::Item::Fn(it) if it.visibility().is_none() => {
+                let func = ctx.sema.to_def(&it)?;
+                if func.is_main(ctx.db) {
+                    return None;
+                }
+                Definition::Function(func)
+            }
+            ast::Item::Struct(it) if it.visibility().is_none() => {
+                Definition::Adt(ctx.sema.to_def(&it)?.into())
+            }
+            ast::Item::Enum(it) if it.visibility().is_none() => {
+                Definition::Adt(ctx.sema.to_def(&it)?.into())
+            }
+            ast::Item::Union(it) if it.visibility().is_none() => {
+                Definition::Adt(ctx.sema.to_def(&it)?.into())
+            }
+            ast::Item::Const(it) if it.visibility().is_none() => {
+                Definition::Const(ctx.sema.to_def(&it)?)
+            }
+            ast::Item::Static(it) if it.visibility().is_none() => {
+                Definition::Static(ctx.sema.to_def(&it)?)
+            }
+            _ => return None,
+        };
+        Some(!def.usages(&ctx.sema).at_least_one())
+    })()
+    .unwrap_or(false)
+}
@@ -1,7 +1,7 @@
 //! Completion for representations.
 
 use ide_db::SymbolKind;
-use syntax::ast;
+use syntax::{ast, AstNode};
 
 use crate::{context::CompletionContext, item::CompletionItem, Completions};
 
@@ -10,8 +10,18 @@ pub(super) fn complete_repr(
     ctx: &CompletionContext<'_>,
     input: ast::TokenTree,
 ) {
+    // integer representations only apply to enums
+    let on_enum = ctx
+        .original_token
+        .parent_ancestors()
+        .find_map(ast::Attr::cast)
+        .and_then(|attr| attr.syntax().parent())
+        .map_or(false, |it| ast::Enum::can_cast(it.kind()));
     if let Some(existing_reprs) = super::parse_comma_sep_expr(input) {
-        for &ReprCompletion { label, snippet, lookup, collides } in REPR_COMPLETIONS {
+        for &ReprCompletion { label, snippet, lookup, collides, enum_only } in REPR_COMPLETIONS {
+            if enum_only && !on_enum {
+                continue;
+            }
             let repr_already_annotated = existing_reprs
                 .iter()
                 .filter_map(|expr| match expr {
@@ -47,28 +57,33 @@ struct ReprCompletion {
     snippet: Option<&'static str>,
     lookup: Option<&'static str>,
     collides: &'static [&'static str],
+    enum_only: bool,
 }
 
 const fn attr(label: &'static str, collides: &'static [&'static str]) -> ReprCompletion {
-    ReprCompletion { label, snippet: None, lookup: None, collides }
+    ReprCompletion { label, snippet: None, lookup: None, collides, enum_only: false }
+}
+
+const fn int_attr(label: &'static str, collides: &'static [&'static str]) -> ReprCompletion {
+    ReprCompletion { label, snippet: None, lookup: None, collides, enum_only: true }
 }
 
 #[rustfmt::skip]
 const REPR_COMPLETIONS: &[ReprCompletion] = &[
-    ReprCompletion { label: "align($0)", snippet: Some("align($0)"), lookup: Some("align"), collides: &["transparent", "packed"] },
+    ReprCompletion { label: "align($0)", snippet: Some("align($0)"), lookup: Some("align"), collides: &["transparent", "packed"], enum_only: false },
     attr("packed", &["transparent", "align"]),
     attr("transparent", &["C", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
     attr("C", &["transparent"]),
-    attr("u8",     &["transparent", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("u16",    &["transparent", "u8", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("u32",    &["transparent", "u8", "u16", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("u64",    &["transparent", "u8", "u16", "u32", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("u128",   &["transparent", "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("usize",  &["transparent", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "isize"]),
-    attr("i8",     &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i16", "i32", "i64", "i128", "isize"]),
-    attr("i16",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i32", "i64", "i128", "isize"]),
-    attr("i32",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i64", "i128", "isize"]),
-    attr("i64",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i128", "isize"]),
-    attr("i28",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "isize"]),
-    attr("isize",  &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128"]),
+    int_attr("u8",     &["transparent", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("u16",    &["transparent", "u8", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("u32",    &["transparent", "u8", "u16", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("u64",    &["transparent", "u8", "u16", "u32", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("u128",   &["transparent", "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("usize",  &["transparent", "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("i8",     &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i16", "i32", "i64", "i128", "isize"]),
+    int_attr("i16",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i32", "i64", "i128", "isize"]),
+    int_attr("i32",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i64", "i128", "isize"]),
+    int_attr("i64",    &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i128", "isize"]),
+    int_attr("i128",   &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "isize"]),
+    int_attr("isize",  &["transparent", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128"]),
 ];
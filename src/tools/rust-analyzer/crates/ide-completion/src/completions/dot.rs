@@ -1,6 +1,8 @@
 //! Completes references after dot (fields and method calls).
 
+use hir::AsAssocItem;
 use ide_db::FxHashSet;
+use itertools::Itertools;
 
 use crate::{
     context::{CompletionContext, DotAccess, DotAccessKind, ExprCtx, PathCompletionCtx, Qualified},
@@ -19,7 +21,7 @@ pub(crate) fn complete_dot(
     };
 
     // Suggest .await syntax for types that implement Future trait
-    if receiver_ty.impls_into_future(ctx.db) {
+    if ctx.config.enable_keyword_completions && receiver_ty.impls_into_future(ctx.db) {
         let mut item =
             CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), "await");
         item.detail("expr.await");
@@ -37,7 +39,13 @@ pub(crate) fn complete_dot(
         is_field_access,
     );
 
-    complete_methods(ctx, receiver_ty, |func| acc.add_method(ctx, dot_access, func, None, None));
+    complete_methods(ctx, receiver_ty, |func, is_ambiguous| {
+        if is_ambiguous {
+            acc.add_method_through_trait(ctx, dot_access, func)
+        } else {
+            acc.add_method(ctx, dot_access, func, None, None)
+        }
+    });
 }
 
 pub(crate) fn complete_undotted_self(
@@ -84,7 +92,7 @@ pub(crate) fn complete_undotted_self(
         |acc, field, ty| acc.add_tuple_field(ctx, Some(hir::known::SELF_PARAM), field, &ty),
         true,
     );
-    complete_methods(ctx, &ty, |func| {
+    complete_methods(ctx, &ty, |func, _| {
         acc.add_method(
             ctx,
             &DotAccess {
@@ -129,12 +137,18 @@ fn complete_fields(
     }
 }
 
+/// Calls `f` with each method of `receiver` and whether its name is shared with a method of
+/// another trait, which can only happen for trait objects.
 fn complete_methods(
     ctx: &CompletionContext<'_>,
     receiver: &hir::Type,
-    mut f: impl FnMut(hir::Function),
+    mut f: impl FnMut(hir::Function, bool),
 ) {
+    // The supertraits of a trait object may define methods of the same name, keep all of them
+    // so that they can be called through their traits instead.
+    let is_trait_object = receiver.strip_references().as_dyn_trait().is_some();
     let mut seen_methods = FxHashSet::default();
+    let mut methods = Vec::new();
     receiver.iterate_method_candidates_with_traits(
         ctx.db,
         &ctx.scope,
@@ -142,12 +156,21 @@ fn complete_methods(
         Some(ctx.module),
         None,
         |func| {
-            if func.self_param(ctx.db).is_some() && seen_methods.insert(func.name(ctx.db)) {
-                f(func);
+            let trait_ = is_trait_object
+                .then(|| func.as_assoc_item(ctx.db)?.containing_trait(ctx.db))
+                .flatten();
+            if func.self_param(ctx.db).is_some() && seen_methods.insert((func.name(ctx.db), trait_))
+            {
+                methods.push(func);
             }
             None::<()>
         },
     );
+    let name_counts = methods.iter().map(|func| func.name(ctx.db)).counts();
+    for func in methods {
+        let is_ambiguous = name_counts[&func.name(ctx.db)] > 1;
+        f(func, is_ambiguous);
+    }
 }
 
 #[cfg(test)]
@@ -156,6 +179,7 @@ mod tests {
 
     use crate::tests::{
         check_edit, completion_list_no_kw, completion_list_no_kw_with_private_editable,
+        get_all_items, TEST_CONFIG,
     };
 
     fn check(ra_fixture: &str, expect: Expect) {
@@ -1236,4 +1260,93 @@ fn foo() {
 "#,
         )
     }
+
+    #[test]
+    fn completes_methods_of_unwrapped_try_expr() {
+        check_edit(
+            "inner_method",
+            r#"
+//- minicore: result, try, from
+struct Inner;
+impl Inner { fn inner_method(&self) {} }
+fn result() -> Result<Inner, ()> { Ok(Inner) }
+fn f() -> Result<(), ()> {
+    result()?.$0
+}
+"#,
+            r#"
+struct Inner;
+impl Inner { fn inner_method(&self) {} }
+fn result() -> Result<Inner, ()> { Ok(Inner) }
+fn f() -> Result<(), ()> {
+    result()?.inner_method()$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_supertrait_methods_of_trait_object() {
+        check(
+            r#"
+trait Super { fn super_method(&self); }
+trait Sub: Super { fn sub_method(&self); }
+fn f(x: &dyn Sub) {
+    x.$0
+}
+"#,
+            expect![[r#"
+                me sub_method()   fn(&self)
+                me super_method() fn(&self)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn disambiguates_trait_object_methods_of_the_same_name() {
+        let fixture = r#"
+trait A { fn foo(&self); }
+trait B { fn foo(&self, x: u32); }
+trait Base: A + B {}
+fn f(x: &dyn Base) {
+    x.$0
+}
+"#;
+        check(
+            fixture,
+            expect![[r#"
+                me foo() (as A)  fn(&self)
+                me foo(…) (as B) fn(&self, u32)
+            "#]],
+        );
+
+        let items = get_all_items(TEST_CONFIG, fixture, None);
+        let insert_text = |label| {
+            let item = items.iter().find(|it| it.label == label).unwrap();
+            item.text_edit.iter().map(|indel| indel.insert.clone()).collect::<String>()
+        };
+        assert_eq!(insert_text("foo()"), "A::foo(x)$0");
+        assert_eq!(insert_text("foo(…)"), "B::foo(x, $0)");
+    }
+
+    #[test]
+    fn disambiguates_trait_object_methods_through_traits_out_of_scope() {
+        let fixture = r#"
+mod m {
+    pub trait A { fn foo(&self); }
+    pub trait B { fn foo(&self, x: u32); }
+    pub trait Base: A + B {}
+}
+fn f(x: &dyn m::Base) {
+    x.$0
+}
+"#;
+        let items = get_all_items(TEST_CONFIG, fixture, None);
+        let insert_text = |label| {
+            let item = items.iter().find(|it| it.label == label).unwrap();
+            item.text_edit.iter().map(|indel| indel.insert.clone()).collect::<String>()
+        };
+        assert_eq!(insert_text("foo()"), "m::A::foo(x)$0");
+        assert_eq!(insert_text("foo(…)"), "m::B::foo(x, $0)");
+    }
 }
@@ -1,12 +1,14 @@
 //! Completion of names from the current scope in expression position.
 
 use hir::ScopeDef;
-use syntax::ast;
+use ide_db::{famous_defs::FamousDefs, imports::import_assets::LocatedImport, SymbolKind};
+use syntax::{ast, SyntaxKind};
 
 use crate::{
-    completions::record::add_default_update,
+    completions::record::{add_default_update, add_local_updates},
     context::{ExprCtx, PathCompletionCtx, Qualified},
-    CompletionContext, Completions,
+    item::CompletionRelevanceTypeMatch,
+    CompletionContext, CompletionItem, CompletionRelevance, Completions,
 };
 
 pub(crate) fn complete_expr_path(
@@ -31,6 +33,7 @@ pub(crate) fn complete_expr_path(
         ref innermost_ret_ty,
         ref impl_,
         in_match_guard,
+        in_array_len,
         ..
     } = expr_ctx;
 
@@ -160,6 +163,11 @@ pub(crate) fn complete_expr_path(
         }
         Qualified::Absolute => acc.add_crate_roots(ctx, path_ctx),
         Qualified::No => {
+            if in_match_guard {
+                // Only a guard can follow the pattern of a match arm, e.g. `Some(x) $0 => ...`.
+                acc.add_keyword_snippet_expr(ctx, incomplete_let, "if", "if $0");
+                return;
+            }
             acc.add_nameref_keywords_with_colon(ctx);
             if let Some(adt) =
                 ctx.expected_type.as_ref().and_then(|ty| ty.strip_references().as_adt())
@@ -233,6 +241,12 @@ pub(crate) fn complete_expr_path(
                         [..] => acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases),
                     }
                 }
+                ScopeDef::ModuleDef(hir::ModuleDef::Function(func)) => {
+                    if let Some(ret_ty) = innermost_ret_ty {
+                        acc.add_fallible_function(ctx, path_ctx, func, Some(name.clone()), ret_ty);
+                    }
+                    acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases)
+                }
                 _ if scope_def_applicable(def) => {
                     acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases)
                 }
@@ -250,12 +264,15 @@ pub(crate) fn complete_expr_path(
                             let missing_fields =
                                 ctx.sema.record_literal_missing_fields(record_expr);
                             if !missing_fields.is_empty() {
+                                add_local_updates(acc, ctx, ty.as_ref());
                                 add_default_update(acc, ctx, ty);
                             }
                         }
                     };
                 }
                 None => {
+                    complete_mem_functions(acc, ctx);
+
                     let mut add_keyword = |kw, snippet| {
                         acc.add_keyword_snippet_expr(ctx, incomplete_let, kw, snippet)
                     };
@@ -267,11 +284,7 @@ pub(crate) fn complete_expr_path(
                     add_keyword("while", "while $1 {\n    $0\n}");
                     add_keyword("while let", "while let $1 = $2 {\n    $0\n}");
                     add_keyword("loop", "loop {\n    $0\n}");
-                    if in_match_guard {
-                        add_keyword("if", "if $0");
-                    } else {
-                        add_keyword("if", "if $1 {\n    $0\n}");
-                    }
+                    add_keyword("if", "if $1 {\n    $0\n}");
                     add_keyword("if let", "if let $1 = $2 {\n    $0\n}");
                     add_keyword("for", "for $1 in $2 {\n    $0\n}");
                     add_keyword("true", "true");
@@ -328,3 +341,92 @@ pub(crate) fn complete_expr_path(
         }
     }
 }
+
+/// Offers calls of the `std::mem` functions swapping out places behind a `&mut`, like
+/// `mem::take(&mut $0)`, importing `std::mem` if it is not in scope yet.
+fn complete_mem_functions(acc: &mut Completions, ctx: &CompletionContext<'_>) -> Option<()> {
+    let cap = ctx.config.snippet_cap?;
+    let famous_defs = FamousDefs(&ctx.sema, ctx.krate);
+    let mem = famous_defs.std_mem()?;
+    let scope = mem.scope(ctx.db, None);
+    let expected = ctx.expected_type.as_ref().filter(|ty| !ty.is_unit() && !ty.is_unknown());
+    let typed = match ctx.original_token.kind() {
+        SyntaxKind::IDENT => ctx.original_token.text().to_lowercase(),
+        _ => String::new(),
+    };
+    let implements_default = |ty: &hir::Type| {
+        famous_defs
+            .core_default_Default()
+            .map_or(false, |default| ty.impls_trait(ctx.db, default, &[]))
+    };
+    // `replace` can only return the expected type if there is a place of that type to replace,
+    // like a local or one of its fields.
+    let has_place_of_type = |expected: &hir::Type| {
+        let mut found = false;
+        ctx.process_all_names_raw(&mut |_, def| {
+            let ScopeDef::Local(local) = def else { return };
+            let ty = local.ty(ctx.db).strip_references();
+            found |= ty.could_unify_with(ctx.db, expected)
+                || ty.fields(ctx.db).iter().any(|(_, ty)| ty.could_unify_with(ctx.db, expected));
+        });
+        found
+    };
+
+    // Only offer the functions that are being typed out, or that return what is expected.
+    let functions: Vec<_> = [
+        ("swap", "(&mut $1, &mut $2)$0", "&mut …, &mut …"),
+        ("replace", "(&mut $1, $2)$0", "&mut …, …"),
+        ("take", "(&mut $0)", "&mut …"),
+    ]
+    .into_iter()
+    .filter(|(name, ..)| {
+        scope.iter().any(|(it, def)| {
+            it.to_smol_str() == *name
+                && matches!(def, ScopeDef::ModuleDef(hir::ModuleDef::Function(_)))
+        })
+    })
+    .filter_map(|(name, args, label_args)| {
+        // `take` and `replace` return the previous value of the place, which is what the
+        // expected type asks for.
+        let returns_expected = match name {
+            "take" => expected.map_or(false, implements_default),
+            "replace" => expected.map_or(false, has_place_of_type),
+            _ => false,
+        };
+        let is_typed =
+            !typed.is_empty() && (name.starts_with(typed.as_str()) || "mem".starts_with(&typed));
+        (is_typed || returns_expected).then_some((name, args, label_args, returns_expected))
+    })
+    .collect();
+    if functions.is_empty() {
+        return None;
+    }
+
+    let mem_def = hir::ModuleDef::Module(mem);
+    let path = ctx.module.find_use_path_prefixed(
+        ctx.db,
+        mem_def,
+        ctx.config.insert_use.prefix_kind,
+        ctx.config.prefer_no_std,
+        ctx.config.prefer_prelude,
+    )?;
+    let qualifier = path.segments().last()?.display(ctx.db).to_string();
+
+    for (name, args, label_args, returns_expected) in functions {
+        let mut item = CompletionItem::new(
+            SymbolKind::Function,
+            ctx.source_range(),
+            format!("{qualifier}::{name}({label_args})"),
+        );
+        item.insert_snippet(cap, format!("{qualifier}::{name}{args}"));
+        item.set_relevance(CompletionRelevance {
+            type_match: returns_expected.then_some(CompletionRelevanceTypeMatch::CouldUnify),
+            ..Default::default()
+        });
+        if path.len() > 1 {
+            item.add_import(LocatedImport::new(path.clone(), mem_def, mem_def));
+        }
+        item.add_to(acc, ctx.db);
+    }
+    Some(())
+}
@@ -1,8 +1,11 @@
 //! Completion of field list position.
 
+use ide_db::imports::import_assets::LocatedImport;
+use syntax::{ast, AstNode};
+
 use crate::{
     context::{PathCompletionCtx, Qualified},
-    CompletionContext, Completions,
+    CompletionContext, CompletionItem, CompletionItemKind, Completions,
 };
 
 pub(crate) fn complete_field_list_tuple_variant(
@@ -40,4 +43,50 @@ pub(crate) fn complete_field_list_record_variant(
         add_keyword("pub(super)", "pub(super)");
         add_keyword("pub", "pub");
     }
+    add_phantom_data_fields(acc, ctx);
+}
+
+/// Offers `PhantomData` fields for the type parameters of the surrounding struct that none of its
+/// fields make use of yet.
+fn add_phantom_data_fields(acc: &mut Completions, ctx: &CompletionContext<'_>) -> Option<()> {
+    let strukt = ctx.token.parent_ancestors().find_map(ast::Struct::cast)?;
+    let generic_params = strukt.generic_param_list()?;
+    let phantom_data =
+        hir::ItemInNs::from(hir::ModuleDef::from(ctx.famous_defs().core_marker_PhantomData()?));
+    let import_path = ctx.module.find_use_path_prefixed(
+        ctx.db,
+        phantom_data,
+        ctx.config.insert_use.prefix_kind,
+        ctx.config.prefer_no_std,
+        ctx.config.prefer_prelude,
+    )?;
+    let field_tys: Vec<ast::Type> = match strukt.field_list()? {
+        ast::FieldList::RecordFieldList(it) => it.fields().filter_map(|it| it.ty()).collect(),
+        ast::FieldList::TupleFieldList(it) => it.fields().filter_map(|it| it.ty()).collect(),
+    };
+
+    for param in generic_params.type_or_const_params() {
+        let ast::TypeOrConstParam::Type(param) = param else { continue };
+        let Some(name) = param.name() else { continue };
+        let is_used = field_tys
+            .iter()
+            .flat_map(|ty| ty.syntax().descendants().filter_map(ast::NameRef::cast))
+            .any(|name_ref| name_ref.text() == name.text());
+        if is_used {
+            continue;
+        }
+
+        let ty = format!("PhantomData<{}>", name.text());
+        let mut item =
+            CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), ty.clone());
+        match ctx.config.snippet_cap {
+            Some(cap) => item.insert_snippet(cap, format!("${{1:_marker}}: {ty}")),
+            None => item.insert_text(format!("_marker: {ty}")),
+        };
+        if import_path.len() > 1 {
+            item.add_import(LocatedImport::new(import_path.clone(), phantom_data, phantom_data));
+        }
+        item.add_to(acc, ctx.db);
+    }
+    None
 }
@@ -1,19 +1,26 @@
 //! See [`import_on_the_fly`].
-use hir::{ItemInNs, ModuleDef};
-use ide_db::imports::{
-    import_assets::{ImportAssets, LocatedImport},
-    insert_use::ImportScope,
+use hir::{AsAssocItem, ItemInNs, ModuleDef, ScopeDef};
+use ide_db::{
+    imports::{
+        import_assets::{ImportAssets, LocatedImport},
+        insert_use::ImportScope,
+    },
+    FxHashSet,
 };
 use itertools::Itertools;
-use syntax::{ast, AstNode, SyntaxNode, T};
+use syntax::{ast, AstNode, SyntaxNode, TextRange, T};
+use text_edit::TextEdit;
 
 use crate::{
     context::{
         CompletionContext, DotAccess, PathCompletionCtx, PathKind, PatternContext, Qualified,
         TypeLocation,
     },
-    render::{render_resolution_with_import, render_resolution_with_import_pat, RenderContext},
-    Completions,
+    render::{
+        render_resolution_with_import, render_resolution_with_import_pat, res_to_kind,
+        RenderContext,
+    },
+    CompletionItem, Completions,
 };
 
 // Feature: Completion With Autoimport
@@ -146,6 +153,62 @@ pub(crate) fn import_on_the_fly_path(
     )
 }
 
+/// Offers to import the item a long qualifier resolves to and to shorten the written path to its
+/// name, e.g. `HashMap::` with `use std::collections::HashMap;` for `std::collections::HashMap::$0`.
+pub(crate) fn import_qualifier_and_shorten(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) -> Option<()> {
+    if !ctx.config.enable_imports_on_the_fly {
+        return None;
+    }
+    let PathCompletionCtx {
+        kind: PathKind::Expr { .. } | PathKind::Type { .. } | PathKind::Pat { .. },
+        qualified:
+            Qualified::With { path: qualifier, resolution: Some(hir::PathResolution::Def(def)), .. },
+        ..
+    } = path_ctx
+    else {
+        return None;
+    };
+    if matches!(def, ModuleDef::Module(_) | ModuleDef::BuiltinType(_)) {
+        return None;
+    }
+    // Shortening is only worth an import for deeply nested paths, like `a::b::Item::`.
+    qualifier.qualifier()?.qualifier()?;
+    ImportScope::find_insert_use_container(qualifier.syntax(), &ctx.sema)?;
+
+    let name = def.name(ctx.db)?;
+    let mut name_in_scope = false;
+    ctx.process_all_names_raw(&mut |it, _| name_in_scope |= it == name);
+    if name_in_scope {
+        return None;
+    }
+
+    let item = ItemInNs::from(*def);
+    let import_path = ctx.module.find_use_path_prefixed(
+        ctx.db,
+        item,
+        ctx.config.insert_use.prefix_kind,
+        ctx.config.prefer_no_std,
+        ctx.config.prefer_prelude,
+    )?;
+
+    let range = TextRange::new(qualifier.syntax().text_range().start(), ctx.source_range().end());
+    let mut builder = CompletionItem::new(
+        res_to_kind(ScopeDef::ModuleDef(*def)),
+        ctx.source_range(),
+        format!("{}::", name.display(ctx.db)),
+    );
+    builder
+        .lookup_by(format!("{qualifier}::"))
+        .text_edit(TextEdit::replace(range, format!("{}::", name.display(ctx.db))))
+        .add_import(LocatedImport::new(import_path, item, item));
+    builder.add_to(acc, ctx.db);
+    Some(())
+}
+
 pub(crate) fn import_on_the_fly_pat(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -344,31 +407,98 @@ fn import_on_the_fly_method(
     }
 
     let user_input_lowercased = potential_import_name.to_lowercase();
+    let search = |import_assets: ImportAssets| {
+        import_assets
+            .search_for_imports(
+                &ctx.sema,
+                ctx.config.insert_use.prefix_kind,
+                ctx.config.prefer_no_std,
+                ctx.config.prefer_prelude,
+            )
+            .into_iter()
+            .filter(|import| {
+                !ctx.is_item_hidden(&import.item_to_import)
+                    && !ctx.is_item_hidden(&import.original_item)
+            })
+            .filter_map(|import| match import.original_item {
+                ItemInNs::Values(hir::ModuleDef::Function(f)) => Some((f, import)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
 
-    import_assets
-        .search_for_imports(
-            &ctx.sema,
-            ctx.config.insert_use.prefix_kind,
-            ctx.config.prefer_no_std,
-            ctx.config.prefer_prelude,
-        )
+    let mut methods: Vec<_> =
+        search(import_assets).into_iter().map(|(f, import)| (f, import, 0)).collect();
+    let input_len = user_input_lowercased.chars().count();
+    if methods.is_empty() && input_len >= 3 {
+        // Nothing matches the input, so it might contain a typo. Search once for all methods that
+        // start like the input does and keep the ones within a typo per four typed characters.
+        let max_typos = input_len / 4 + 1;
+        let first_char = user_input_lowercased.chars().next()?.to_string();
+        let import_assets = ImportAssets::for_fuzzy_method_call(
+            ctx.module,
+            dot_access.receiver_ty.as_ref()?.original.clone(),
+            first_char,
+            position.clone(),
+        )?;
+        methods = search(import_assets)
+            .into_iter()
+            .filter_map(|(f, import)| {
+                let name = f.name(ctx.db).to_smol_str().to_lowercase();
+                let typos = typo_distance(&user_input_lowercased, &name);
+                (typos <= max_typos).then_some((f, import, typos))
+            })
+            .collect();
+    }
+
+    let receiver_traits: FxHashSet<_> = dot_access
+        .receiver_ty
+        .as_ref()
+        .map(|it| hir::Impl::all_for_type(ctx.db, it.original.strip_references()))
         .into_iter()
-        .filter(|import| {
-            !ctx.is_item_hidden(&import.item_to_import)
-                && !ctx.is_item_hidden(&import.original_item)
-        })
-        .sorted_by_key(|located_import| {
-            compute_fuzzy_completion_order_key(&located_import.import_path, &user_input_lowercased)
+        .flatten()
+        .filter_map(|impl_| impl_.trait_(ctx.db))
+        .collect();
+    methods
+        .into_iter()
+        .sorted_by_cached_key(|(f, import, typos)| {
+            // Methods of impls for the receiver type itself go before the ones of blanket impls,
+            // ties are broken by name and path so that the cap below always keeps the same items.
+            let implemented_for_receiver = f
+                .as_assoc_item(ctx.db)
+                .and_then(|it| it.containing_trait(ctx.db))
+                .map_or(false, |trait_| receiver_traits.contains(&trait_));
+            (
+                !implemented_for_receiver,
+                *typos,
+                compute_fuzzy_completion_order_key(&import.import_path, &user_input_lowercased),
+                f.name(ctx.db).to_smol_str(),
+                import.import_path.display(ctx.db).to_string(),
+            )
         })
-        .for_each(|import| match import.original_item {
-            ItemInNs::Values(hir::ModuleDef::Function(f)) => {
-                acc.add_method_with_import(ctx, dot_access, f, import);
-            }
-            _ => (),
-        });
+        .take(ctx.config.flyimport_max_results.unwrap_or(usize::MAX))
+        .for_each(|(f, import, _)| acc.add_method_with_import(ctx, dot_access, f, import));
     Some(())
 }
 
+/// The edit distance between `input` and the closest prefix of `name`, so that the correctly typed
+/// start of a name has no typos.
+fn typo_distance(input: &str, name: &str) -> usize {
+    let name: Vec<char> = name.chars().collect();
+    // `row[j]` is the distance between the input read so far and the first `j` chars of the name.
+    let mut row: Vec<usize> = (0..=name.len()).collect();
+    for (i, input_char) in input.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &name_char) in name.iter().enumerate() {
+            let substitution = diagonal + usize::from(input_char != name_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row.into_iter().min().unwrap_or(0)
+}
+
 fn import_name(ctx: &CompletionContext<'_>) -> String {
     let token_kind = ctx.token.kind();
     if matches!(token_kind, T![.] | T![::]) {
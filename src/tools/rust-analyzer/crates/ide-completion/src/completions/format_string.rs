@@ -2,7 +2,9 @@
 
 use ide_db::syntax_helpers::format_string::is_format_string;
 use itertools::Itertools;
-use syntax::{ast, AstToken, TextRange, TextSize};
+use syntax::{
+    ast, AstNode, AstToken, NodeOrToken, SmolStr, SyntaxKind::IDENT, TextRange, TextSize, T,
+};
 
 use crate::{context::CompletionContext, CompletionItem, CompletionItemKind, Completions};
 
@@ -26,6 +28,17 @@ pub(crate) fn format_string(
         // escaped brace
         Some(((_, '{'), (_, '{'))) => return,
         Some(((idx, '{'), _)) => lit_start + TextSize::from(idx as u32 + 1),
+        Some(_) => {
+            // Unlike the scan above, which stops at an underscore, names in counts may have them.
+            let ident_start =
+                prefix.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_').len();
+            if is_in_count_position(prefix, ident_start) {
+                let suffix = &original.text()[usize::from(cursor_in_lit)..];
+                let range = TextRange::new(lit_start + TextSize::from(ident_start as u32), cursor);
+                complete_count_args(acc, ctx, original, range, !suffix.starts_with('$'));
+            }
+            return;
+        }
         _ => return,
     };
 
@@ -36,6 +49,68 @@ pub(crate) fn format_string(
     })
 }
 
+/// Whether the identifier starting at `ident_start` is the width or precision of a format spec,
+/// like `wid` in `{x:>wid$}` or `prec` in `{x:.prec$}`. An empty identifier right after the colon,
+/// like in `{x:$0`, also counts.
+fn is_in_count_position(prefix: &str, ident_start: usize) -> bool {
+    let (before, ident) = prefix.split_at(ident_start);
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        return false;
+    }
+    let Some(open) = before.rfind('{') else { return false };
+    let spec = &before[open..];
+    let is_escaped = before[..open].ends_with('{');
+    !is_escaped
+        && !spec.contains('}')
+        && spec.contains(':')
+        && before.ends_with(|c: char| matches!(c, ':' | '.' | '<' | '^' | '>' | '+' | '-' | '#'))
+}
+
+/// Completes the `usize` locals usable as a width or precision, with the `$` that marks them as
+/// names. Locals already passed as positional arguments to the macro are left out.
+fn complete_count_args(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    original: &ast::String,
+    source_range: TextRange,
+    insert_dollar: bool,
+) {
+    let positional_args = positional_args(original);
+    ctx.locals.iter().for_each(|(name, local)| {
+        let name = name.to_smol_str();
+        if !local.ty(ctx.db).is_usize() || positional_args.contains(&name) {
+            return;
+        }
+        let mut item = CompletionItem::new(CompletionItemKind::Binding, source_range, name.clone());
+        if insert_dollar {
+            item.insert_text(format!("{name}$"));
+        }
+        item.add_to(acc, ctx.db);
+    })
+}
+
+/// The names passed as plain positional arguments after the format string, like `a` and `b` in
+/// `format_args!("{} {}", a, b, c = 1)`.
+fn positional_args(original: &ast::String) -> Vec<SmolStr> {
+    let Some(tt) = original.syntax().parent().and_then(ast::TokenTree::cast) else {
+        return Vec::new();
+    };
+    let args = tt
+        .syntax()
+        .children_with_tokens()
+        .skip_while(|it| it.as_token() != Some(original.syntax()))
+        .filter(|it| !it.kind().is_trivia() && it.kind() != T![')']);
+    let mut res = Vec::new();
+    for (_, mut arg) in &args.skip(1).group_by(|it| it.kind() == T![,]) {
+        if let (Some(NodeOrToken::Token(token)), None) = (arg.next(), arg.next()) {
+            if token.kind() == IDENT {
+                res.push(SmolStr::from(token.text()));
+            }
+        }
+    }
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
@@ -113,4 +188,82 @@ fn main() {
 "#,
         );
     }
+
+    #[test]
+    fn completes_usize_locals_as_counts() {
+        check_edit(
+            "width",
+            r#"
+//- minicore: fmt
+fn main() {
+    let x = 1.0;
+    let width = 8usize;
+    format_args!("{x:wid$0$}");
+}
+"#,
+            r#"
+fn main() {
+    let x = 1.0;
+    let width = 8usize;
+    format_args!("{x:width$}");
+}
+"#,
+        );
+        check_edit(
+            "width",
+            r#"
+//- minicore: fmt
+fn main() {
+    let x = 1.0;
+    let width = 8usize;
+    format_args!("{x:>$0}");
+}
+"#,
+            r#"
+fn main() {
+    let x = 1.0;
+    let width = 8usize;
+    format_args!("{x:>width$}");
+}
+"#,
+        );
+        check_edit(
+            "max_width",
+            r#"
+//- minicore: fmt
+fn main() {
+    let x = 1.0;
+    let max_width = 8usize;
+    format_args!("{x:>max_w$0}");
+}
+"#,
+            r#"
+fn main() {
+    let x = 1.0;
+    let max_width = 8usize;
+    format_args!("{x:>max_width$}");
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn count_completion_skips_non_usizes_and_positional_args() {
+        check(
+            r#"
+//- minicore: fmt
+fn main() {
+    let x = 1.0;
+    let width = 8usize;
+    let prec = 2usize;
+    let count = 2u32;
+    let name = "x";
+    format_args!("{x:.$0} {}", width);
+}
+"#,
+            expect![[r#"
+                bn prec
+            "#]],
+        );
+    }
 }
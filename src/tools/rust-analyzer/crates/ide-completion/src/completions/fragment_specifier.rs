@@ -0,0 +1,97 @@
+//! Completes fragment specifiers of `macro_rules!` metavariables, like `($x:$0) => {}`.
+
+use crate::{
+    completions::Completions, context::CompletionContext, CompletionItem, CompletionItemKind,
+};
+
+const FRAGMENT_SPECIFIERS: &[&str] = &[
+    "block",
+    "expr",
+    "ident",
+    "item",
+    "lifetime",
+    "literal",
+    "meta",
+    "pat",
+    "pat_param",
+    "path",
+    "stmt",
+    "tt",
+    "ty",
+    "vis",
+];
+
+pub(crate) fn complete_fragment_specifier(acc: &mut Completions, ctx: &CompletionContext<'_>) {
+    for &specifier in FRAGMENT_SPECIFIERS {
+        CompletionItem::new(CompletionItemKind::Keyword, ctx.source_range(), specifier)
+            .add_to(acc, ctx.db);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::tests::{check_edit, completion_list};
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_after_metavariable_colon() {
+        check(
+            r#"
+macro_rules! m {
+    ($x:$0) => {};
+}
+"#,
+            expect![[r#"
+                kw block
+                kw expr
+                kw ident
+                kw item
+                kw lifetime
+                kw literal
+                kw meta
+                kw pat
+                kw pat_param
+                kw path
+                kw stmt
+                kw tt
+                kw ty
+                kw vis
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_in_repetition() {
+        check_edit(
+            "expr",
+            r#"
+macro_rules! m {
+    ($($x:e$0),*) => {};
+}
+"#,
+            r#"
+macro_rules! m {
+    ($($x:expr),*) => {};
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_completion_in_transcriber() {
+        check(
+            r#"
+macro_rules! m {
+    ($x:ident) => { let $x:$0 };
+}
+"#,
+            expect![[]],
+        );
+    }
+}
@@ -1,5 +1,10 @@
 //! Completion of paths and keywords at item list position.
 
+use syntax::{
+    ast,
+    SyntaxKind::{ASSOC_ITEM_LIST, ITEM_LIST, SOURCE_FILE, STMT_LIST},
+};
+
 use crate::{
     context::{ExprCtx, ItemListKind, PathCompletionCtx, Qualified},
     CompletionContext, Completions,
@@ -126,8 +131,23 @@ fn add_keywords(acc: &mut Completions, ctx: &CompletionContext<'_>, kind: Option
             }
 
             add_keyword("fn", "fn $1($2) {\n    $0\n}");
+            if !in_trait && has_const_fn_siblings(ctx) {
+                add_keyword("const fn", "const fn $1($2) {\n    $0\n}");
+            }
             add_keyword("unsafe", "unsafe");
             add_keyword("const", "const $0");
         }
     }
 }
+
+/// Whether the items next to the cursor include a `const fn`, making another one a likely item.
+fn has_const_fn_siblings(ctx: &CompletionContext<'_>) -> bool {
+    let Some(items) = ctx
+        .token
+        .parent_ancestors()
+        .find(|it| matches!(it.kind(), SOURCE_FILE | ITEM_LIST | ASSOC_ITEM_LIST | STMT_LIST))
+    else {
+        return false;
+    };
+    items.children().filter_map(ast::Fn::cast).any(|it| it.const_token().is_some())
+}
@@ -37,14 +37,14 @@
     syntax_helpers::insert_whitespace_into_node, traits::get_missing_assoc_items, SymbolKind,
 };
 use syntax::{
-    ast::{self, edit_in_place::AttrsOwnerEdit, HasTypeBounds},
+    ast::{self, edit::IndentLevel, edit_in_place::AttrsOwnerEdit, HasTypeBounds},
     AstNode, SyntaxElement, SyntaxKind, TextRange, T,
 };
 use text_edit::TextEdit;
 
 use crate::{
-    context::PathCompletionCtx, CompletionContext, CompletionItem, CompletionItemKind,
-    CompletionRelevance, Completions,
+    context::PathCompletionCtx, item::Builder, CompletionContext, CompletionItem,
+    CompletionItemKind, CompletionRelevance, Completions,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -150,24 +150,34 @@ fn complete_trait_impl(
     impl_def: &ast::Impl,
 ) {
     if let Some(hir_impl) = ctx.sema.to_def(impl_def) {
-        get_missing_assoc_items(&ctx.sema, impl_def)
+        if hir_impl.trait_(ctx.db).is_none() {
+            if matches!(kind, ImplCompletionKind::All | ImplCompletionKind::Fn) {
+                add_inherent_fn_stubs(acc, ctx, replacement_range, hir_impl);
+            }
+            return;
+        }
+        let missing_items: Vec<_> = get_missing_assoc_items(&ctx.sema, impl_def)
             .into_iter()
             .filter(|item| ctx.check_stability(Some(&item.attrs(ctx.db))))
-            .for_each(|item| {
-                use self::ImplCompletionKind::*;
-                match (item, kind) {
-                    (hir::AssocItem::Function(func), All | Fn) => {
-                        add_function_impl(acc, ctx, replacement_range, func, hir_impl)
-                    }
-                    (hir::AssocItem::TypeAlias(type_alias), All | TypeAlias) => {
-                        add_type_alias_impl(acc, ctx, replacement_range, type_alias, hir_impl)
-                    }
-                    (hir::AssocItem::Const(const_), All | Const) => {
-                        add_const_impl(acc, ctx, replacement_range, const_, hir_impl)
-                    }
-                    _ => {}
+            .collect();
+        if kind == ImplCompletionKind::All {
+            add_all_missing_items_impl(acc, ctx, replacement_range, &missing_items, hir_impl);
+        }
+        missing_items.into_iter().for_each(|item| {
+            use self::ImplCompletionKind::*;
+            match (item, kind) {
+                (hir::AssocItem::Function(func), All | Fn) => {
+                    add_function_impl(acc, ctx, replacement_range, func, hir_impl)
                 }
-            });
+                (hir::AssocItem::TypeAlias(type_alias), All | TypeAlias) => {
+                    add_type_alias_impl(acc, ctx, replacement_range, type_alias, hir_impl)
+                }
+                (hir::AssocItem::Const(const_), All | Const) => {
+                    add_const_impl(acc, ctx, replacement_range, const_, hir_impl)
+                }
+                _ => {}
+            }
+        });
     }
 }
 
@@ -178,6 +188,39 @@ fn add_function_impl(
     func: hir::Function,
     impl_def: hir::Impl,
 ) {
+    if let Some(source) = ctx.sema.source(func) {
+        let assoc_item = ast::AssocItem::Fn(source.value);
+        if let Some(transformed_item) = get_transformed_assoc_item(ctx, assoc_item, impl_def) {
+            let transformed_fn = match transformed_item {
+                ast::AssocItem::Fn(func) => func,
+                _ => unreachable!(),
+            };
+
+            let function_decl = function_declaration(&transformed_fn, source.file_id.is_macro());
+            let comment = if ctx.config.override_comment && transformed_fn.body().is_some() {
+                "\n    // overriding default"
+            } else {
+                ""
+            };
+            let mut item =
+                function_impl_item(ctx, replacement_range, func, &function_decl, comment);
+            item.set_relevance(CompletionRelevance {
+                is_item_from_trait: true,
+                ..Default::default()
+            });
+            item.add_to(acc, ctx.db);
+        }
+    }
+}
+
+/// Renders a function with the given declaration and an empty body, to be filled in by the user.
+fn function_impl_item(
+    ctx: &CompletionContext<'_>,
+    replacement_range: TextRange,
+    func: hir::Function,
+    function_decl: &str,
+    comment: &str,
+) -> Builder {
     let fn_name = func.name(ctx.db);
 
     let label = format!(
@@ -193,29 +236,78 @@ fn add_function_impl(
     };
 
     let mut item = CompletionItem::new(completion_kind, replacement_range, label);
-    item.lookup_by(format!("fn {}", fn_name.display(ctx.db)))
-        .set_documentation(func.docs(ctx.db))
-        .set_relevance(CompletionRelevance { is_item_from_trait: true, ..Default::default() });
-
-    if let Some(source) = ctx.sema.source(func) {
-        let assoc_item = ast::AssocItem::Fn(source.value);
-        if let Some(transformed_item) = get_transformed_assoc_item(ctx, assoc_item, impl_def) {
-            let transformed_fn = match transformed_item {
-                ast::AssocItem::Fn(func) => func,
-                _ => unreachable!(),
-            };
+    item.lookup_by(format!("fn {}", fn_name.display(ctx.db))).set_documentation(func.docs(ctx.db));
+    match ctx.config.snippet_cap {
+        Some(cap) => {
+            let snippet = format!("{function_decl} {{{comment}\n    $0\n}}");
+            item.snippet_edit(cap, TextEdit::replace(replacement_range, snippet));
+        }
+        None => {
+            let header = format!("{function_decl} {{{comment}");
+            item.text_edit(TextEdit::replace(replacement_range, header));
+        }
+    };
+    item
+}
 
-            let function_decl = function_declaration(&transformed_fn, source.file_id.is_macro());
-            match ctx.config.snippet_cap {
-                Some(cap) => {
-                    let snippet = format!("{function_decl} {{\n    $0\n}}");
-                    item.snippet_edit(cap, TextEdit::replace(replacement_range, snippet));
-                }
-                None => {
-                    let header = format!("{function_decl} {{");
-                    item.text_edit(TextEdit::replace(replacement_range, header));
-                }
-            };
+/// Offers the methods of the traits next to an inherent `impl` that its type doesn't implement as
+/// inherent methods, for types that mirror a trait's API without implementing it.
+fn add_inherent_fn_stubs(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    replacement_range: TextRange,
+    impl_def: hir::Impl,
+) {
+    if !ctx.config.enable_inherent_stubs {
+        return;
+    }
+    let self_ty = impl_def.self_ty(ctx.db);
+    // The function being completed doesn't count, its name is still being typed.
+    let existing_fns: Vec<_> = impl_def
+        .items(ctx.db)
+        .into_iter()
+        .filter_map(|item| match item {
+            hir::AssocItem::Function(func) => Some(func),
+            _ => None,
+        })
+        .filter(|func| {
+            ctx.sema.source(*func).map_or(true, |source| {
+                !source.value.syntax().text_range().contains_range(replacement_range)
+            })
+        })
+        .map(|func| func.name(ctx.db))
+        .collect();
+    let sibling_traits =
+        impl_def.module(ctx.db).declarations(ctx.db).into_iter().filter_map(|def| match def {
+            hir::ModuleDef::Trait(trait_) => Some(trait_),
+            _ => None,
+        });
+    for trait_ in sibling_traits {
+        let Some(trait_source) = ctx.sema.source(trait_) else { continue };
+        // The methods of generic traits and ones that use the trait's associated items don't
+        // make sense outside of the trait.
+        if trait_source.value.generic_param_list().is_some()
+            || self_ty.impls_trait(ctx.db, trait_, &[])
+        {
+            continue;
+        }
+        for item in trait_.items(ctx.db) {
+            let hir::AssocItem::Function(func) = item else { continue };
+            let fn_name = func.name(ctx.db);
+            if existing_fns.contains(&fn_name) || !ctx.check_stability(Some(&func.attrs(ctx.db))) {
+                continue;
+            }
+            let Some(source) = ctx.sema.source(func) else { continue };
+            let func_source = source.value.clone_for_update();
+            if func_source.syntax().descendants().filter_map(ast::Path::cast).any(|path| {
+                path.qualifier().and_then(|it| it.as_single_segment()?.self_type_token()).is_some()
+            }) {
+                continue;
+            }
+            func_source.remove_attrs_and_docs();
+            let function_decl = function_declaration(&func_source, source.file_id.is_macro());
+            let mut item = function_impl_item(ctx, replacement_range, func, &function_decl, "");
+            item.detail(format!("inherent stub of `{}`", trait_.name(ctx.db).display(ctx.db)));
             item.add_to(acc, ctx.db);
         }
     }
@@ -269,30 +361,10 @@ fn add_type_alias_impl(
                 _ => unreachable!(),
             };
 
-            let start = transformed_ty.syntax().text_range().start();
-
-            let end = if let Some(end) =
-                transformed_ty.colon_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else if let Some(end) = transformed_ty.eq_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else if let Some(end) =
-                transformed_ty.semicolon_token().map(|tok| tok.text_range().start())
-            {
-                end
-            } else {
+            let Some(decl) = type_alias_declaration(&transformed_ty) else {
                 return;
             };
 
-            let len = end - start;
-            let mut decl = transformed_ty.syntax().text().slice(..len).to_string();
-            if !decl.ends_with(' ') {
-                decl.push(' ');
-            }
-            decl.push_str("= ");
-
             match ctx.config.snippet_cap {
                 Some(cap) => {
                     let snippet = format!("{decl}$0;");
@@ -307,6 +379,89 @@ fn add_type_alias_impl(
     }
 }
 
+/// Adds a single completion that stubs out all of `missing_items` that have no default at once.
+fn add_all_missing_items_impl(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    replacement_range: TextRange,
+    missing_items: &[hir::AssocItem],
+    impl_def: hir::Impl,
+) -> Option<()> {
+    let cap = ctx.config.snippet_cap?;
+
+    let mut stubs = Vec::with_capacity(missing_items.len());
+    for &item in missing_items {
+        let tabstop = stubs.len() + 1;
+        let stub = match item {
+            hir::AssocItem::Function(func) => {
+                let source = ctx.sema.source(func)?;
+                if source.value.body().is_some() {
+                    continue;
+                }
+                let is_macro = source.file_id.is_macro();
+                let ast::AssocItem::Fn(func) =
+                    get_transformed_assoc_item(ctx, ast::AssocItem::Fn(source.value), impl_def)?
+                else {
+                    unreachable!()
+                };
+                let decl = function_declaration(&func, is_macro);
+                format!("{decl} {{\n    ${{{tabstop}:todo!()}}\n}}")
+            }
+            hir::AssocItem::TypeAlias(type_alias) => {
+                let source = ctx.sema.source(type_alias)?;
+                if source.value.ty().is_some() {
+                    continue;
+                }
+                let ast::AssocItem::TypeAlias(type_alias) = get_transformed_assoc_item(
+                    ctx,
+                    ast::AssocItem::TypeAlias(source.value),
+                    impl_def,
+                )?
+                else {
+                    unreachable!()
+                };
+                let decl = type_alias_declaration(&type_alias)?;
+                format!("{decl}${tabstop};")
+            }
+            hir::AssocItem::Const(const_) => {
+                let source = ctx.sema.source(const_)?;
+                if source.value.body().is_some() {
+                    continue;
+                }
+                let is_macro = source.file_id.is_macro();
+                let ast::AssocItem::Const(const_) =
+                    get_transformed_assoc_item(ctx, ast::AssocItem::Const(source.value), impl_def)?
+                else {
+                    unreachable!()
+                };
+                let decl = make_const_compl_syntax(&const_, is_macro);
+                format!("{decl} ${tabstop};")
+            }
+        };
+        stubs.push(stub);
+    }
+    if stubs.len() < 2 {
+        return None;
+    }
+    // The first stub replaces the item being typed, which is already indented.
+    let indent = IndentLevel::from_node(ctx.sema.source(impl_def)?.value.syntax()) + 1;
+    let stubs: Vec<_> =
+        stubs.iter().map(|stub| stub.replace('\n', &format!("\n{indent}"))).collect();
+
+    let mut item = CompletionItem::new(
+        CompletionItemKind::Snippet,
+        replacement_range,
+        "implement all missing items",
+    );
+    item.set_relevance(CompletionRelevance { is_item_from_trait: true, ..Default::default() })
+        .snippet_edit(
+            cap,
+            TextEdit::replace(replacement_range, stubs.join(&format!("\n\n{indent}"))),
+        );
+    item.add_to(acc, ctx.db);
+    Some(())
+}
+
 fn add_const_impl(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -348,6 +503,25 @@ fn add_const_impl(
     }
 }
 
+/// Renders `type Name<..> = ` for the given type alias, dropping its bounds and value.
+fn type_alias_declaration(type_alias: &ast::TypeAlias) -> Option<String> {
+    let start = type_alias.syntax().text_range().start();
+    let end = type_alias
+        .colon_token()
+        .or_else(|| type_alias.eq_token())
+        .or_else(|| type_alias.semicolon_token())?
+        .text_range()
+        .start();
+
+    let len = end - start;
+    let mut decl = type_alias.syntax().text().slice(..len).to_string();
+    if !decl.ends_with(' ') {
+        decl.push(' ');
+    }
+    decl.push_str("= ");
+    Some(decl)
+}
+
 fn make_const_compl_syntax(const_: &ast::Const, needs_whitespace: bool) -> String {
     let const_ = if needs_whitespace {
         insert_whitespace_into_node::insert_ws_into(const_.syntax().clone())
@@ -396,7 +570,10 @@ fn function_declaration(node: &ast::Fn, needs_whitespace: bool) -> String {
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::tests::{check_edit, completion_list_no_kw};
+    use crate::{
+        tests::{check_edit, check_edit_with_config, completion_list_no_kw, TEST_CONFIG},
+        CompletionConfig,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list_no_kw(ra_fixture);
@@ -688,6 +865,96 @@ fn test() {
         );
     }
 
+    #[test]
+    fn unsafe_function_keeps_qualifier() {
+        check_edit(
+            "fn test",
+            r#"
+trait Test {
+    unsafe fn test(ptr: *const u8);
+}
+struct T;
+
+impl Test for T {
+    fn t$0
+}
+"#,
+            r#"
+trait Test {
+    unsafe fn test(ptr: *const u8);
+}
+struct T;
+
+impl Test for T {
+    unsafe fn test(ptr: *const u8) {
+    $0
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn async_function_keeps_qualifier() {
+        check_edit(
+            "fn test",
+            r#"
+//- minicore: future
+trait Test {
+    async fn test(&self) -> u32;
+}
+struct T;
+
+impl Test for T {
+    fn t$0
+}
+"#,
+            r#"
+trait Test {
+    async fn test(&self) -> u32;
+}
+struct T;
+
+impl Test for T {
+    async fn test(&self) -> u32 {
+    $0
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn function_in_impl_allowing_clippy_lint() {
+        check_edit(
+            "fn test",
+            r#"
+trait Test {
+    fn test(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8);
+}
+struct T;
+
+#[allow(clippy::too_many_arguments)]
+impl Test for T {
+    fn t$0
+}
+"#,
+            r#"
+trait Test {
+    fn test(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8);
+}
+struct T;
+
+#[allow(clippy::too_many_arguments)]
+impl Test for T {
+    fn test(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) {
+    $0
+}
+}
+"#,
+        );
+    }
+
     #[test]
     fn generic_fn() {
         check_edit(
@@ -788,6 +1055,44 @@ impl Test for () {
         );
     }
 
+    #[test]
+    fn iterator_impl_stubs() {
+        check_edit(
+            "type Item",
+            r#"
+//- minicore: iterator
+struct Foo;
+impl Iterator for Foo {
+    $0
+}
+"#,
+            r#"
+struct Foo;
+impl Iterator for Foo {
+    type Item = $0;
+}
+"#,
+        );
+        check_edit(
+            "fn next",
+            r#"
+//- minicore: iterator
+struct Foo;
+impl Iterator for Foo {
+    $0
+}
+"#,
+            r#"
+struct Foo;
+impl Iterator for Foo {
+    fn next(&mut self) -> Option<Self::Item> {
+    $0
+}
+}
+"#,
+        );
+    }
+
     #[test]
     fn associated_const() {
         check_edit(
@@ -1393,6 +1698,180 @@ trait Tr {
 impl Tr for () {
     type Item = $0;
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn implement_all_missing_items() {
+        check_edit(
+            "implement all missing items",
+            r#"
+trait Tr {
+    type Item;
+    const LEN: usize;
+    fn provided() {}
+    fn get(&self, idx: usize) -> Self::Item;
+    fn len(&self) -> usize;
+}
+
+impl Tr for () {
+    type Item = u8;
+
+    $0
+}
+"#,
+            r#"
+trait Tr {
+    type Item;
+    const LEN: usize;
+    fn provided() {}
+    fn get(&self, idx: usize) -> Self::Item;
+    fn len(&self) -> usize;
+}
+
+impl Tr for () {
+    type Item = u8;
+
+    const LEN: usize = $1;
+
+    fn get(&self, idx: usize) -> Self::Item {
+        ${2:todo!()}
+    }
+
+    fn len(&self) -> usize {
+        ${3:todo!()}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn override_comment_on_default_methods() {
+        let fixture = r#"
+trait Tr {
+    fn provided(&self) {}
+}
+
+impl Tr for () {
+    fn p$0
+}
+"#;
+        check_edit_with_config(
+            CompletionConfig { override_comment: true, ..TEST_CONFIG },
+            "fn provided",
+            fixture,
+            r#"
+trait Tr {
+    fn provided(&self) {}
+}
+
+impl Tr for () {
+    fn provided(&self) {
+    // overriding default
+    $0
+}
+}
+"#,
+        );
+        check_edit(
+            "fn provided",
+            fixture,
+            r#"
+trait Tr {
+    fn provided(&self) {}
+}
+
+impl Tr for () {
+    fn provided(&self) {
+    $0
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_override_comment_on_required_methods() {
+        check_edit_with_config(
+            CompletionConfig { override_comment: true, ..TEST_CONFIG },
+            "fn required",
+            r#"
+trait Tr {
+    fn required(&self);
+}
+
+impl Tr for () {
+    fn r$0
+}
+"#,
+            r#"
+trait Tr {
+    fn required(&self);
+}
+
+impl Tr for () {
+    fn required(&self) {
+    $0
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inherent_stubs_of_sibling_trait_methods() {
+        let fixture = r#"
+trait Shape {
+    fn area(&self) -> f64;
+}
+struct Square(f64);
+impl Square {
+    fn a$0
+}
+"#;
+        check_edit_with_config(
+            CompletionConfig { enable_inherent_stubs: true, ..TEST_CONFIG },
+            "fn area",
+            fixture,
+            r#"
+trait Shape {
+    fn area(&self) -> f64;
+}
+struct Square(f64);
+impl Square {
+    fn area(&self) -> f64 {
+    $0
+}
+}
+"#,
+        );
+        check(fixture, expect![[r#""#]]);
+    }
+
+    #[test]
+    fn inherent_stubs_without_snippet_support() {
+        check_edit_with_config(
+            CompletionConfig { enable_inherent_stubs: true, snippet_cap: None, ..TEST_CONFIG },
+            "fn area",
+            r#"
+trait Shape {
+    fn area(&self) -> f64;
+}
+struct Square(f64);
+impl Square {
+    fn a$0
+}
+"#,
+            r#"
+trait Shape {
+    fn area(&self) -> f64;
+}
+struct Square(f64);
+impl Square {
+    fn area(&self) -> f64 {
+}
 "#,
         );
     }
@@ -216,6 +216,23 @@ fn main() {
         )
     }
 
+    #[test]
+    fn only_guard_after_match_arm_pattern() {
+        check(
+            r"
+//- minicore: option
+fn main() {
+    match Some(0) {
+        Some(x) $0
+    }
+}
+",
+            expect![[r#"
+                kw if
+            "#]],
+        );
+    }
+
     #[test]
     fn if_completion_in_match_arm_expr() {
         check_edit(
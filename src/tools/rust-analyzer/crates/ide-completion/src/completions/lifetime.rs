@@ -8,7 +8,7 @@
 //! show up for normal completions, or they won't show completions other than lifetimes depending
 //! on the fixture input.
 use hir::{known, ScopeDef};
-use syntax::{ast, TokenText};
+use syntax::{ast, match_ast, AstNode, TokenText};
 
 use crate::{
     completions::Completions,
@@ -46,6 +46,21 @@ pub(crate) fn complete_lifetime(
             acc.add_lifetime(ctx, name);
         }
     });
+    // lifetimes of higher-ranked binders like `for<'a> fn(&'a u8)` are not part of the scope
+    ctx.token
+        .parent_ancestors()
+        .filter_map(|it| {
+            match_ast! {
+                match it {
+                    ast::ForType(it) => it.generic_param_list(),
+                    ast::WherePred(it) => it.generic_param_list(),
+                    _ => None,
+                }
+            }
+        })
+        .flat_map(|it| it.lifetime_params())
+        .filter_map(|it| it.lifetime())
+        .for_each(|it| acc.add_lifetime(ctx, hir::Name::new_lifetime(&it)));
     if param_lifetime.is_none() {
         acc.add_lifetime(ctx, known::STATIC_LIFETIME);
     }
@@ -226,6 +241,76 @@ fn foo<'footime, 'lifetime: 'a$0>() {}
         );
     }
 
+    #[test]
+    fn complete_lifetime_in_fn_ptr_ty() {
+        check(
+            r#"
+fn foo<'lifetime>(f: fn(&'a$0 u8)) {}
+"#,
+            expect![[r#"
+                lt 'lifetime
+                lt 'static
+            "#]],
+        );
+        check(
+            r#"
+fn foo(f: for<'item> fn(&'a$0 u8)) {}
+"#,
+            expect![[r#"
+                lt 'item
+                lt 'static
+            "#]],
+        );
+        check(
+            r#"
+fn foo<F>(f: F) where for<'item> F: Fn(&'a$0 u8) {}
+"#,
+            expect![[r#"
+                lt 'item
+                lt 'static
+            "#]],
+        );
+    }
+
+    #[test]
+    fn complete_lifetime_in_gat() {
+        check(
+            r#"
+trait Tr {
+    type Item<'a> where Self: 'a$0;
+}
+"#,
+            expect![[r#"
+                lt 'a
+                lt 'static
+            "#]],
+        );
+        check(
+            r#"
+trait Tr {
+    type Item<'item, 'b: 'a$0>;
+}
+"#,
+            expect![[r#"
+                lt 'item
+            "#]],
+        );
+        check(
+            r#"
+trait Tr {
+    type Item<'a>;
+}
+impl Tr for () {
+    type Item<'item> = &'a$0 u8;
+}
+"#,
+            expect![[r#"
+                lt 'item
+                lt 'static
+            "#]],
+        );
+    }
+
     #[test]
     fn check_label_edit() {
         check_edit(
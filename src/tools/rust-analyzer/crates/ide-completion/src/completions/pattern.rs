@@ -1,11 +1,14 @@
 //! Completes constants and paths in unqualified patterns.
 
 use hir::{db::DefDatabase, AssocItem, ScopeDef};
-use syntax::ast::Pat;
+use syntax::{
+    ast::{self, Pat},
+    AstNode,
+};
 
 use crate::{
     context::{PathCompletionCtx, PatternContext, PatternRefutability, Qualified},
-    CompletionContext, Completions,
+    CompletionContext, CompletionItem, CompletionItemKind, Completions,
 };
 
 /// Completes constants and paths in unqualified patterns.
@@ -45,10 +48,43 @@ pub(crate) fn complete_pattern(
 
     let refutable = pattern_ctx.refutability == PatternRefutability::Refutable;
     let single_variant_enum = |enum_: hir::Enum| ctx.db.enum_data(enum_.into()).variants.len() == 1;
+    // Only offer constants that can actually be compared against the scrutinee.
+    let const_matches_scrutinee = |konst: hir::Const| match &ctx.expected_type {
+        Some(ty) if !ty.is_unknown() => {
+            konst.ty(ctx.db).strip_references().could_unify_with(ctx.db, &ty.strip_references())
+        }
+        _ => true,
+    };
 
-    if let Some(hir::Adt::Enum(e)) =
-        ctx.expected_type.as_ref().and_then(|ty| ty.strip_references().as_adt())
-    {
+    let scrutinee_enum =
+        match ctx.expected_type.as_ref().and_then(|ty| ty.strip_references().as_adt()) {
+            Some(hir::Adt::Enum(e)) => Some(e),
+            _ => None,
+        };
+    // The other alternatives of a match arm's or-pattern, or the previous arms, already cover some
+    // variants. The missing variants are only known for the top-level pattern of a match arm.
+    let covered_variants: Option<Vec<hir::Variant>> =
+        match (&pattern_ctx.parent_pat, scrutinee_enum) {
+            (Some(Pat::OrPat(or_pat)), Some(e))
+                if or_pat
+                    .syntax()
+                    .parent()
+                    .map_or(false, |it| ast::MatchArm::can_cast(it.kind())) =>
+            {
+                Some(
+                    e.variants(ctx.db)
+                        .into_iter()
+                        .filter(|variant| !pattern_ctx.missing_variants.contains(variant))
+                        .collect(),
+                )
+            }
+            _ => None,
+        };
+    let is_covered = |variant: hir::Variant| {
+        covered_variants.as_ref().map_or(false, |covered| covered.contains(&variant))
+    };
+
+    if let Some(e) = scrutinee_enum {
         if refutable || single_variant_enum(e) {
             super::enum_variants_with_paths(
                 acc,
@@ -56,12 +92,50 @@ pub(crate) fn complete_pattern(
                 e,
                 &pattern_ctx.impl_,
                 |acc, ctx, variant, path| {
-                    acc.add_qualified_variant_pat(ctx, pattern_ctx, variant, path);
+                    if !is_covered(variant) {
+                        acc.add_qualified_variant_pat(ctx, pattern_ctx, variant, path);
+                    }
                 },
             );
         }
     }
 
+    // Inherent consts of the scrutinee type, like `Color::RED`, can be matched against as well.
+    if let Some(ty) = ctx.expected_type.as_ref().map(|ty| ty.strip_references()) {
+        let qualifier = ty.as_adt().filter(|_| refutable).and_then(|adt| {
+            ctx.module.find_use_path(
+                ctx.db,
+                hir::ModuleDef::Adt(adt),
+                ctx.config.prefer_no_std,
+                ctx.config.prefer_prelude,
+            )
+        });
+        if let Some(qualifier) = qualifier {
+            hir::Impl::all_for_type(ctx.db, ty)
+                .into_iter()
+                .filter(|impl_| impl_.trait_(ctx.db).is_none())
+                .flat_map(|impl_| impl_.items(ctx.db))
+                .for_each(|item| match item {
+                    AssocItem::Const(konst) if const_matches_scrutinee(konst) => {
+                        acc.add_qualified_const(ctx, konst, &qualifier)
+                    }
+                    _ => (),
+                });
+        }
+    }
+
+    // Integer and char scrutinees are commonly matched against ranges of values.
+    let in_range_pat = matches!(pattern_ctx.parent_pat, Some(Pat::RangePat(_)));
+    if let (Some(cap), Some(ty)) = (ctx.config.snippet_cap, &ctx.expected_type) {
+        let ty = ty.strip_references();
+        if refutable && !in_range_pat && (ty.is_int_or_uint() || ty.is_char()) {
+            let mut item =
+                CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), "start..=end");
+            item.insert_snippet(cap, "$1..=$2");
+            item.add_to(acc, ctx.db);
+        }
+    }
+
     // FIXME: ideally, we should look at the type we are matching against and
     // suggest variants + auto-imports
     ctx.process_all_names(&mut |name, res, _| {
@@ -71,6 +145,7 @@ pub(crate) fn complete_pattern(
                     acc.add_struct_pat(ctx, pattern_ctx, strukt, Some(name.clone()));
                     true
                 }
+                hir::ModuleDef::Variant(variant) if is_covered(variant) => false,
                 hir::ModuleDef::Variant(variant)
                     if refutable || single_variant_enum(variant.parent_enum(ctx.db)) =>
                 {
@@ -78,7 +153,7 @@ pub(crate) fn complete_pattern(
                     true
                 }
                 hir::ModuleDef::Adt(hir::Adt::Enum(e)) => refutable || single_variant_enum(e),
-                hir::ModuleDef::Const(..) => refutable,
+                hir::ModuleDef::Const(konst) => refutable && const_matches_scrutinee(konst),
                 hir::ModuleDef::Module(..) => true,
                 hir::ModuleDef::Macro(mac) => mac.is_fn_like(ctx.db),
                 _ => false,
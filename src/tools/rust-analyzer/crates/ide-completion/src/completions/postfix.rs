@@ -2,12 +2,14 @@
 
 mod format_like;
 
+use hir::{HasAttrs, ItemInNs, ModuleDef, ScopeDef};
 use ide_db::{
     documentation::{Documentation, HasDocs},
-    imports::insert_use::ImportScope,
+    imports::{import_assets::LocatedImport, insert_use::ImportScope},
     ty_filter::TryEnum,
     SnippetCap,
 };
+use stdx::format_to;
 use syntax::{
     ast::{self, make, AstNode, AstToken},
     SyntaxKind::{BLOCK_EXPR, EXPR_STMT, FOR_EXPR, IF_EXPR, LOOP_EXPR, STMT_LIST, WHILE_EXPR},
@@ -19,6 +21,7 @@
     completions::postfix::format_like::add_format_like_completions,
     context::{CompletionContext, DotAccess, DotAccessKind},
     item::{Builder, CompletionRelevancePostfixMatch},
+    render::function::collect_turbofish,
     CompletionItem, CompletionItemKind, CompletionRelevance, Completions, SnippetScope,
 };
 
@@ -49,10 +52,20 @@ pub(crate) fn complete_postfix(
 
     let cap = match ctx.config.snippet_cap {
         Some(it) => it,
-        None => return,
+        None => {
+            // Without tab stops to fill in, only the bare `match` skeleton is worth offering.
+            let (dot_receiver, node_to_replace_with) = include_references(dot_receiver);
+            let receiver_text =
+                get_receiver_text(&node_to_replace_with, receiver_is_ambiguous_float_literal);
+            if let Some(postfix_snippet) = build_postfix_snippet_builder(ctx, None, &dot_receiver) {
+                postfix_snippet("match", "match expr {}", &format!("match {receiver_text} {{}}"))
+                    .add_to(acc, ctx.db);
+            }
+            return;
+        }
     };
 
-    let postfix_snippet = match build_postfix_snippet_builder(ctx, cap, dot_receiver) {
+    let postfix_snippet = match build_postfix_snippet_builder(ctx, Some(cap), dot_receiver) {
         Some(it) => it,
         None => return,
     };
@@ -107,6 +120,9 @@ pub(crate) fn complete_postfix(
                 .add_to(acc, ctx.db);
             }
         }
+        if !receiver_ty.is_reference() && propagates_try_enum(ctx, dot_receiver, try_enum) {
+            postfix_snippet("q", "expr?", &format!("{receiver_text}?")).add_to(acc, ctx.db);
+        }
     } else if receiver_ty.is_bool() || receiver_ty.is_unknown() {
         postfix_snippet("if", "if expr {}", &format!("if {receiver_text} {{\n    $0\n}}"))
             .add_to(acc, ctx.db);
@@ -124,8 +140,57 @@ pub(crate) fn complete_postfix(
         }
     }
 
+    if let Some(iterator) = ctx.famous_defs().core_iter_Iterator() {
+        if receiver_ty.impls_trait(ctx.db, iterator, &[]) {
+            add_iterator_adapters(acc, ctx, &postfix_snippet, receiver_ty, &receiver_text);
+        } else if let Some(into_iterator) = ctx.famous_defs().core_iter_IntoIterator() {
+            if receiver_ty.impls_trait(ctx.db, into_iterator, &[]) {
+                add_collect(acc, ctx, &postfix_snippet, &receiver_text, true);
+            }
+        }
+    }
+
     postfix_snippet("ref", "&expr", &format!("&{receiver_text}")).add_to(acc, ctx.db);
     postfix_snippet("refm", "&mut expr", &format!("&mut {receiver_text}")).add_to(acc, ctx.db);
+    if receiver_ty.is_int_or_uint() || receiver_ty.is_float() || receiver_ty.is_raw_ptr() {
+        postfix_snippet("cast", "expr as _", &format!("{receiver_text} as $0")).add_to(acc, ctx.db);
+    }
+    let is_signed = receiver_ty
+        .strip_references()
+        .as_builtin()
+        .map_or(false, |it| it.is_int() || it.is_float());
+    if is_signed {
+        // The receiver of a `.` binds tighter than a unary `-` already, it only needs the
+        // parentheses it was written with.
+        postfix_snippet("neg", "-expr", &format!("-{receiver_text}")).add_to(acc, ctx.db);
+    }
+    if receiver_ty.is_int_or_uint() {
+        for method in ["checked_add", "overflowing_add", "saturating_add", "wrapping_add"] {
+            let mut item = postfix_snippet(
+                method,
+                &format!("expr.{method}(…)"),
+                &format!("{receiver_text}.{method}($0)"),
+            );
+            // The integer types define these as inherent methods, which should stay on top even
+            // when their name is typed out.
+            item.set_relevance(CompletionRelevance {
+                postfix_match: Some(CompletionRelevancePostfixMatch::NonExact),
+                ..Default::default()
+            });
+            item.add_to(acc, ctx.db);
+        }
+    }
+
+    // Owned containers of a slice, like `Vec<T>`, need to be sliced explicitly where a slice is
+    // expected but inference can't see through the deref coercion, e.g. for generic arguments.
+    let expects_slice =
+        ctx.expected_type.as_ref().and_then(|ty| ty.remove_ref()).map_or(false, |ty| ty.is_slice());
+    if expects_slice
+        && !receiver_ty.is_reference()
+        && receiver_ty.autoderef(ctx.db).skip(1).any(|ty| ty.is_slice())
+    {
+        postfix_snippet("slice", "&expr[..]", &format!("&{receiver_text}[..]")).add_to(acc, ctx.db);
+    }
 
     let mut unsafe_should_be_wrapped = true;
     if dot_receiver.syntax().kind() == BLOCK_EXPR {
@@ -149,7 +214,7 @@ pub(crate) fn complete_postfix(
     let (dot_receiver, node_to_replace_with) = include_references(dot_receiver);
     let receiver_text =
         get_receiver_text(&node_to_replace_with, receiver_is_ambiguous_float_literal);
-    let postfix_snippet = match build_postfix_snippet_builder(ctx, cap, &dot_receiver) {
+    let postfix_snippet = match build_postfix_snippet_builder(ctx, Some(cap), &dot_receiver) {
         Some(it) => it,
         None => return,
     };
@@ -158,28 +223,21 @@ pub(crate) fn complete_postfix(
         add_custom_postfix_completions(acc, ctx, &postfix_snippet, &receiver_text);
     }
 
-    match try_enum {
-        Some(try_enum) => match try_enum {
-            TryEnum::Result => {
-                postfix_snippet(
-                    "match",
-                    "match expr {}",
-                    &format!("match {receiver_text} {{\n    Ok(${{1:_}}) => {{$2}},\n    Err(${{3:_}}) => {{$0}},\n}}"),
-                )
-                .add_to(acc, ctx.db);
-            }
-            TryEnum::Option => {
-                postfix_snippet(
-                    "match",
-                    "match expr {}",
-                    &format!(
-                        "match {receiver_text} {{\n    Some(${{1:_}}) => {{$2}},\n    None => {{$0}},\n}}"
-                    ),
-                )
-                .add_to(acc, ctx.db);
-            }
-        },
-        None => {
+    if try_enum.is_some() {
+        add_anyhow_context(acc, ctx, &postfix_snippet, &dot_receiver, &receiver_text);
+    }
+
+    match receiver_ty.strip_references().as_adt() {
+        Some(hir::Adt::Enum(enum_)) => {
+            let arms = enum_match_arms(ctx, cap, enum_);
+            postfix_snippet(
+                "match",
+                "match expr {}",
+                &format!("match {receiver_text} {{\n{arms}}}"),
+            )
+            .add_to(acc, ctx.db);
+        }
+        _ => {
             postfix_snippet(
                 "match",
                 "match expr {}",
@@ -212,6 +270,99 @@ pub(crate) fn complete_postfix(
     }
 }
 
+/// One arm per variant of `enum_` with tab stops on the bindings and the arm bodies, like
+/// `Ok(${1:x}) => $2,`, and a trailing catch-all arm if the enum is non-exhaustive.
+fn enum_match_arms(ctx: &CompletionContext<'_>, _cap: SnippetCap, enum_: hir::Enum) -> String {
+    let db = ctx.db;
+    let is_result = ctx.famous_defs().core_result_Result() == Some(enum_);
+    let mut arms = String::new();
+    let mut tab_stop = 0;
+    let mut next_tab_stop = || {
+        tab_stop += 1;
+        tab_stop
+    };
+    for variant in enum_.variants(db) {
+        let name = variant.name(db);
+        let path = ctx
+            .module
+            .find_use_path(
+                db,
+                ModuleDef::Variant(variant),
+                ctx.config.prefer_no_std,
+                ctx.config.prefer_prelude,
+            )
+            .map(|it| it.display(db).to_string())
+            .unwrap_or_else(|| format!("{}::{}", enum_.name(db).display(db), name.display(db)));
+        let kind = variant.kind(db);
+        let fields = variant.fields(db);
+        let mut bindings = Vec::with_capacity(fields.len());
+        for (idx, field) in fields.iter().enumerate() {
+            let binding = match kind {
+                hir::StructKind::Record => {
+                    let field = field.name(db).display(db).to_string();
+                    format!("{field}: ${{{}:{field}}}", next_tab_stop())
+                }
+                _ => {
+                    let binding = match fields.len() {
+                        1 if is_result && name.as_str() == Some("Err") => "e".to_owned(),
+                        1 => "x".to_owned(),
+                        _ => format!("x{idx}"),
+                    };
+                    format!("${{{}:{binding}}}", next_tab_stop())
+                }
+            };
+            bindings.push(binding);
+        }
+        let pat = match kind {
+            hir::StructKind::Record => format!("{path} {{ {} }}", bindings.join(", ")),
+            hir::StructKind::Tuple => format!("{path}({})", bindings.join(", ")),
+            hir::StructKind::Unit => path,
+        };
+        format_to!(arms, "    {pat} => ${},\n", next_tab_stop());
+    }
+    let is_non_exhaustive =
+        enum_.attrs(db).by_key("non_exhaustive").exists() && enum_.module(db).krate() != ctx.krate;
+    if is_non_exhaustive {
+        format_to!(arms, "    _ => ${},\n", next_tab_stop());
+    }
+    arms
+}
+
+fn add_iterator_adapters(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    postfix_snippet: impl Fn(&str, &str, &str) -> Builder,
+    receiver_ty: &hir::Type,
+    receiver_text: &str,
+) {
+    let double_ended = ctx.famous_defs().core_iter_DoubleEndedIterator();
+    if double_ended.map_or(false, |it| receiver_ty.impls_trait(ctx.db, it, &[])) {
+        postfix_snippet("rev", "expr.rev()", &format!("{receiver_text}.rev()")).add_to(acc, ctx.db);
+    }
+    postfix_snippet("enumerate", "expr.enumerate()", &format!("{receiver_text}.enumerate()"))
+        .add_to(acc, ctx.db);
+    add_collect(acc, ctx, &postfix_snippet, receiver_text, false);
+}
+
+/// Adds a `collect` postfix, converting the receiver with `into_iter()` first if it is a
+/// collection rather than an iterator.
+fn add_collect(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    postfix_snippet: impl Fn(&str, &str, &str) -> Builder,
+    receiver_text: &str,
+    needs_into_iter: bool,
+) {
+    let (iter_text, detail) = if needs_into_iter {
+        (format!("{receiver_text}.into_iter()"), "expr.into_iter().collect::<_>()")
+    } else {
+        (receiver_text.to_owned(), "expr.collect::<_>()")
+    };
+
+    let snippet = format!("{iter_text}.collect{}", collect_turbofish(ctx));
+    postfix_snippet("collect", detail, &snippet).add_to(acc, ctx.db);
+}
+
 fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal: bool) -> String {
     let text = if receiver_is_ambiguous_float_literal {
         let text = receiver.syntax().text();
@@ -263,7 +414,7 @@ fn include_references(initial_element: &ast::Expr) -> (ast::Expr, ast::Expr) {
 
 fn build_postfix_snippet_builder<'ctx>(
     ctx: &'ctx CompletionContext<'_>,
-    cap: SnippetCap,
+    cap: Option<SnippetCap>,
     receiver: &'ctx ast::Expr,
 ) -> Option<impl Fn(&str, &str, &str) -> Builder + 'ctx> {
     let receiver_range = ctx.sema.original_range_opt(receiver.syntax())?.range;
@@ -277,14 +428,18 @@ fn build_postfix_snippet_builder<'ctx>(
     // can't be annotated for the closure, hence fix it by constructing it without the Option first
     fn build<'ctx>(
         ctx: &'ctx CompletionContext<'_>,
-        cap: SnippetCap,
+        cap: Option<SnippetCap>,
         delete_range: TextRange,
     ) -> impl Fn(&str, &str, &str) -> Builder + 'ctx {
         move |label, detail, snippet| {
             let edit = TextEdit::replace(delete_range, snippet.to_string());
             let mut item =
                 CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), label);
-            item.detail(detail).snippet_edit(cap, edit);
+            item.detail(detail);
+            match cap {
+                Some(cap) => item.snippet_edit(cap, edit),
+                None => item.text_edit(edit),
+            };
             let postfix_match = if ctx.original_token.text() == label {
                 cov_mark::hit!(postfix_exact_match_is_high_priority);
                 Some(CompletionRelevancePostfixMatch::Exact)
@@ -300,6 +455,90 @@ fn build<'ctx>(
     Some(build(ctx, cap, delete_range))
 }
 
+/// Whether `?` can be applied to a receiver of the given kind, that is whether the enclosing
+/// function returns the same `Try` type.
+fn propagates_try_enum(
+    ctx: &CompletionContext<'_>,
+    dot_receiver: &ast::Expr,
+    kind: &TryEnum,
+) -> bool {
+    let fn_ = dot_receiver
+        .syntax()
+        .ancestors()
+        .take_while(|it| !ast::ClosureExpr::can_cast(it.kind()))
+        .find_map(ast::Fn::cast);
+    let ret_ty = fn_.and_then(|it| ctx.sema.to_def(&it)).map(|it| it.ret_type(ctx.db));
+    matches!(
+        (ret_ty.and_then(|ty| TryEnum::from_ty(&ctx.sema, &ty)), kind),
+        (Some(TryEnum::Result), TryEnum::Result) | (Some(TryEnum::Option), TryEnum::Option)
+    )
+}
+
+/// Offers `context` and `with_context` for `Option` and `Result` receivers inside functions
+/// returning `anyhow::Result`, importing `anyhow::Context` if it is not in scope yet.
+fn add_anyhow_context(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    postfix_snippet: impl Fn(&str, &str, &str) -> Builder,
+    dot_receiver: &ast::Expr,
+    receiver_text: &str,
+) -> Option<()> {
+    let anyhow = ctx
+        .krate
+        .dependencies(ctx.db)
+        .into_iter()
+        .find(|dep| dep.name.to_smol_str() == "anyhow")?
+        .krate;
+
+    let fn_ = dot_receiver.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let ret_ty = ctx.sema.to_def(&fn_)?.ret_type(ctx.db);
+    if !matches!(TryEnum::from_ty(&ctx.sema, &ret_ty)?, TryEnum::Result) {
+        return None;
+    }
+    let error_ty = ret_ty.type_arguments().nth(1)?;
+    if error_ty.as_adt()?.module(ctx.db).krate() != anyhow {
+        return None;
+    }
+
+    let context_trait =
+        anyhow.root_module().scope(ctx.db, None).into_iter().find_map(|(name, def)| match def {
+            ScopeDef::ModuleDef(ModuleDef::Trait(it)) if name.to_smol_str() == "Context" => {
+                Some(it)
+            }
+            _ => None,
+        })?;
+    let import = if ctx.traits_in_scope().iter().any(|&it| hir::Trait::from(it) == context_trait) {
+        None
+    } else {
+        let item = ItemInNs::from(ModuleDef::from(context_trait));
+        let path = ctx.module.find_use_path_prefixed(
+            ctx.db,
+            item,
+            ctx.config.insert_use.prefix_kind,
+            ctx.config.prefer_no_std,
+            ctx.config.prefer_prelude,
+        )?;
+        Some(LocatedImport::new(path, item, item))
+    };
+
+    let snippets = [
+        ("context", "expr.context(\"…\")?", format!("{receiver_text}.context(\"$0\")?")),
+        (
+            "with_context",
+            "expr.with_context(|| …)?",
+            format!("{receiver_text}.with_context(|| $0)?"),
+        ),
+    ];
+    for (label, detail, snippet) in snippets {
+        let mut item = postfix_snippet(label, detail, &snippet);
+        if let Some(import) = import.clone() {
+            item.add_import(import);
+        }
+        item.add_to(acc, ctx.db);
+    }
+    Some(())
+}
+
 fn add_custom_postfix_completions(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -333,8 +572,8 @@ mod tests {
     use expect_test::{expect, Expect};
 
     use crate::{
-        tests::{check_edit, check_edit_with_config, completion_list, TEST_CONFIG},
-        CompletionConfig, Snippet,
+        tests::{check_edit, check_edit_with_config, completion_list, get_all_items, TEST_CONFIG},
+        CompletionConfig, CompletionItemKind, Snippet,
     };
 
     fn check(ra_fixture: &str, expect: Expect) {
@@ -408,20 +647,209 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn box    Box::new(expr)
-                sn call   function(expr)
-                sn dbg    dbg!(expr)
-                sn dbgr   dbg!(&expr)
-                sn let    let
-                sn letm   let mut
-                sn match  match expr {}
-                sn ref    &expr
-                sn refm   &mut expr
-                sn unsafe unsafe {}
+                sn box             Box::new(expr)
+                sn call            function(expr)
+                sn cast            expr as _
+                sn checked_add     expr.checked_add(…)
+                sn dbg             dbg!(expr)
+                sn dbgr            dbg!(&expr)
+                sn let             let
+                sn letm            let mut
+                sn match           match expr {}
+                sn overflowing_add expr.overflowing_add(…)
+                sn ref             &expr
+                sn refm            &mut expr
+                sn saturating_add  expr.saturating_add(…)
+                sn unsafe          unsafe {}
+                sn wrapping_add    expr.wrapping_add(…)
             "#]],
         )
     }
 
+    #[test]
+    fn postfix_integer_arithmetic_methods() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: int_impl
+fn main() {
+    let len: u32 = 12;
+    let next = len.wrapping_add$0;
+}
+"#,
+            None,
+        );
+        let find = |kind| {
+            items.iter().find(|it| it.kind == kind && it.lookup() == "wrapping_add").unwrap()
+        };
+        let postfix = find(CompletionItemKind::Snippet);
+        let method = find(CompletionItemKind::Method);
+        let insert = postfix.text_edit.iter().map(|indel| indel.insert.clone()).collect::<String>();
+        assert_eq!(insert, "len.wrapping_add($0)");
+        assert!(postfix.relevance.score() < method.relevance.score());
+    }
+
+    #[test]
+    fn postfix_neg() {
+        check_edit(
+            "neg",
+            r#"
+fn main() {
+    let delta: i32 = 12;
+    let back = delta.$0;
+}
+"#,
+            r#"
+fn main() {
+    let delta: i32 = 12;
+    let back = -delta;
+}
+"#,
+        );
+        check_edit(
+            "neg",
+            r#"
+fn main() {
+    let (x, y): (f64, f64) = (1.0, 2.0);
+    let back = (x + y).$0;
+}
+"#,
+            r#"
+fn main() {
+    let (x, y): (f64, f64) = (1.0, 2.0);
+    let back = -(x + y);
+}
+"#,
+        );
+        let actual = completion_list(
+            r#"
+fn main() {
+    let len: u32 = 12;
+    len.$0
+}
+"#,
+        );
+        assert!(!actual.contains("sn neg"), "{actual}");
+    }
+
+    #[test]
+    fn postfix_cast() {
+        check_edit(
+            "cast",
+            r#"
+fn main() {
+    let len: u32 = 12;
+    let idx = len.$0;
+}
+"#,
+            r#"
+fn main() {
+    let len: u32 = 12;
+    let idx = len as $0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_anyhow_context() {
+        check_edit(
+            "context",
+            r#"
+//- minicore: option, result
+//- /main.rs crate:main deps:anyhow
+fn read() -> Result<u32, ()> { Ok(0) }
+
+fn main() -> anyhow::Result<()> {
+    let n = read().$0;
+    Ok(())
+}
+//- /anyhow.rs crate:anyhow
+pub struct Error;
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+pub trait Context<T, E> {
+    fn context<C>(self, context: C) -> Result<T, Error>;
+    fn with_context<C, F: FnOnce() -> C>(self, f: F) -> Result<T, Error>;
+}
+"#,
+            r#"
+use anyhow::Context;
+
+fn read() -> Result<u32, ()> { Ok(0) }
+
+fn main() -> anyhow::Result<()> {
+    let n = read().context("$0")?;
+    Ok(())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_question_mark() {
+        check_edit(
+            "q",
+            r#"
+//- minicore: result
+fn read() -> Result<u32, ()> { Ok(0) }
+fn main() -> Result<(), ()> {
+    let n = read().$0;
+    Ok(())
+}
+"#,
+            r#"
+fn read() -> Result<u32, ()> { Ok(0) }
+fn main() -> Result<(), ()> {
+    let n = read()?;
+    Ok(())
+}
+"#,
+        );
+
+        let actual = completion_list(
+            r#"
+//- minicore: result
+fn read() -> Result<u32, ()> { Ok(0) }
+fn main() {
+    let n = read().$0;
+}
+"#,
+        );
+        assert!(!actual.contains("sn q "), "{actual}");
+    }
+
+    #[test]
+    fn postfix_slice_of_owned_container() {
+        check_edit(
+            "slice",
+            r#"
+//- minicore: deref
+struct Vec<T>(T);
+impl<T> core::ops::Deref for Vec<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] { loop {} }
+}
+fn sum(values: &[u32]) -> u32 { 0 }
+fn main() {
+    let v = Vec(0u32);
+    sum(v.$0);
+}
+"#,
+            r#"
+struct Vec<T>(T);
+impl<T> core::ops::Deref for Vec<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] { loop {} }
+}
+fn sum(values: &[u32]) -> u32 { 0 }
+fn main() {
+    let v = Vec(0u32);
+    sum(&v[..]);
+}
+"#,
+        );
+    }
+
     #[test]
     fn let_middle_block() {
         check(
@@ -486,14 +914,276 @@ fn main() {
 fn main() {
     let bar = Ok(true);
     match bar {
-    Ok(${1:_}) => {$2},
-    Err(${3:_}) => {$0},
+    Ok(${1:x}) => $2,
+    Err(${3:e}) => $4,
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn enum_match_expands_variant_arms() {
+        check_edit(
+            "match",
+            r#"
+enum Shape {
+    Unit,
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+fn main(shape: &Shape) {
+    shape.$0
+}
+"#,
+            r#"
+enum Shape {
+    Unit,
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+fn main(shape: &Shape) {
+    match shape {
+    Shape::Unit => $1,
+    Shape::Circle(${2:x}) => $3,
+    Shape::Rect { w: ${4:w}, h: ${5:h} } => $6,
+}
+}
+"#,
+        );
+        check_edit(
+            "match",
+            r#"
+//- /main.rs crate:main deps:dep
+use dep::Event;
+fn main(event: Event) {
+    event.$0
+}
+//- /dep.rs crate:dep
+#[non_exhaustive]
+pub enum Event {
+    Tick,
+}
+"#,
+            r#"
+use dep::Event;
+fn main(event: Event) {
+    match event {
+    Event::Tick => $1,
+    _ => $2,
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn option_match() {
+        check_edit(
+            "match",
+            r#"
+//- minicore: option
+fn main() {
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Some(true);
+    match bar {
+    Some(${1:x}) => $2,
+    None => $3,
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn enum_match_without_snippets() {
+        check_edit_with_config(
+            CompletionConfig { snippet_cap: None, ..TEST_CONFIG },
+            "match",
+            r#"
+enum Shape {
+    Unit,
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+fn main(shape: &Shape) {
+    shape.$0
+}
+"#,
+            r#"
+enum Shape {
+    Unit,
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+fn main(shape: &Shape) {
+    match shape {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn iterator_adapters() {
+        let fixture = r#"
+//- minicore: iterator, double_ended_iterator
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl core::iter::DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<u32> { None }
+}
+fn main() {
+    Iter.$0
+}
+"#;
+        check_edit(
+            "rev",
+            fixture,
+            r#"
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl core::iter::DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<u32> { None }
+}
+fn main() {
+    Iter.rev()
+}
+"#,
+        );
+        check_edit(
+            "enumerate",
+            fixture,
+            r#"
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl core::iter::DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<u32> { None }
+}
+fn main() {
+    Iter.enumerate()
+}
+"#,
+        );
+        check_edit(
+            "collect",
+            fixture,
+            r#"
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl core::iter::DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<u32> { None }
 }
+fn main() {
+    Iter.collect::<$0>()
 }
 "#,
         );
     }
 
+    #[test]
+    fn iterator_collect_uses_expected_type() {
+        check_edit(
+            "collect",
+            r#"
+//- minicore: iterator
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+struct Bag;
+fn main() {
+    let bag: Bag = Iter.$0;
+}
+"#,
+            r#"
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+struct Bag;
+fn main() {
+    let bag: Bag = Iter.collect::<Bag>()$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collection_collect_converts_into_iterator() {
+        check_edit(
+            "collect",
+            r#"
+//- minicore: iterators
+struct Vec<T>(T);
+impl<T> IntoIterator for Vec<T> {
+    type Item = T;
+    type IntoIter = core::iter::Repeat<T>;
+    fn into_iter(self) -> Self::IntoIter { loop {} }
+}
+struct HashSet<T>(T);
+fn main() {
+    let v = Vec(0u32);
+    let set: HashSet<u32> = v.$0;
+}
+"#,
+            r#"
+struct Vec<T>(T);
+impl<T> IntoIterator for Vec<T> {
+    type Item = T;
+    type IntoIter = core::iter::Repeat<T>;
+    fn into_iter(self) -> Self::IntoIter { loop {} }
+}
+struct HashSet<T>(T);
+fn main() {
+    let v = Vec(0u32);
+    let set: HashSet<u32> = v.into_iter().collect::<HashSet<_>>()$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_iterator_adapters_without_double_ended_or_iterator() {
+        let actual = completion_list(
+            r#"
+//- minicore: iterator, double_ended_iterator
+struct Iter;
+impl Iterator for Iter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+fn main() {
+    Iter.$0
+}
+"#,
+        );
+        assert!(!actual.contains("sn rev"), "{actual}");
+        assert!(actual.contains("sn enumerate"), "{actual}");
+
+        let actual = completion_list(r#"fn main() { let bar = 92; bar.$0 }"#);
+        assert!(!actual.contains("sn enumerate"), "{actual}");
+        assert!(!actual.contains("sn collect"), "{actual}");
+    }
+
     #[test]
     fn postfix_completion_works_for_ambiguous_float_literal() {
         check_edit("refm", r#"fn main() { 42.$0 }"#, r#"fn main() { &mut 42 }"#)
@@ -46,7 +46,7 @@ pub(crate) fn add_format_like_completions(
     cap: SnippetCap,
     receiver_text: &ast::String,
 ) {
-    let postfix_snippet = match build_postfix_snippet_builder(ctx, cap, dot_receiver) {
+    let postfix_snippet = match build_postfix_snippet_builder(ctx, Some(cap), dot_receiver) {
         Some(it) => it,
         None => return,
     };
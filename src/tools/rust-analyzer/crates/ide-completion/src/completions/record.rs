@@ -62,6 +62,7 @@ pub(crate) fn complete_record_expr_fields(
 
             if !missing_fields.is_empty() {
                 cov_mark::hit!(functional_update_field);
+                add_local_updates(acc, ctx, ty.as_ref());
                 add_default_update(acc, ctx, ty);
             }
             if dot_prefix {
@@ -102,6 +103,28 @@ pub(crate) fn add_default_update(
     }
 }
 
+/// Offers the locals of the record's type as the base of a functional update, e.g. `..base`.
+pub(crate) fn add_local_updates(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    ty: Option<&hir::TypeInfo>,
+) {
+    let Some(ty) = ty else { return };
+    for (name, local) in ctx.locals.iter() {
+        if local.ty(ctx.db) != ty.original {
+            continue;
+        }
+        let completion_text = format!("..{}", name.display(ctx.db));
+        let mut item =
+            CompletionItem::new(SymbolKind::Field, ctx.source_range(), completion_text.as_str());
+        let completion_text =
+            completion_text.strip_prefix(ctx.token.text()).unwrap_or(&completion_text);
+        item.insert_text(completion_text)
+            .set_relevance(CompletionRelevance { is_local: true, ..Default::default() });
+        item.add_to(acc, ctx.db);
+    }
+}
+
 fn complete_fields(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -271,6 +294,37 @@ fn new() -> submod::Foo {
         )
     }
 
+    #[test]
+    fn literal_struct_self_in_constructor() {
+        check_edit(
+            "Self{}",
+            r#"
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn new(x: i32, y: i32) -> Self {
+        Se$0
+    }
+}
+"#,
+            r#"
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x: ${1:()}, y: ${2:()} }$0
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn literal_struct_completion_from_sub_modules() {
         check_edit(
@@ -1,9 +1,21 @@
 //! This file provides snippet completions, like `pd` => `eprintln!(...)`.
 
-use ide_db::{documentation::Documentation, imports::insert_use::ImportScope, SnippetCap};
+use hir::ScopeDef;
+use ide_db::{
+    active_parameter::callable_for_token, documentation::Documentation,
+    imports::insert_use::ImportScope, ty_filter::TryEnum, SnippetCap,
+};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, HasAttrs, HasGenericParams, HasName},
+    AstNode, Direction, SyntaxKind,
+};
+use text_edit::TextEdit;
 
 use crate::{
-    context::{ExprCtx, ItemListKind, PathCompletionCtx, Qualified},
+    context::{
+        ExprCtx, ItemListKind, PathCompletionCtx, PatternContext, Qualified, TypeAscriptionTarget,
+    },
     item::Builder,
     CompletionContext, CompletionItem, CompletionItemKind, Completions, SnippetScope,
 };
@@ -12,7 +24,7 @@ pub(crate) fn complete_expr_snippet(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
     path_ctx: &PathCompletionCtx,
-    &ExprCtx { in_block_expr, .. }: &ExprCtx,
+    &ExprCtx { in_block_expr, in_unsafe_context, in_array_len, .. }: &ExprCtx,
 ) {
     if !matches!(path_ctx.qualified, Qualified::No) {
         return;
@@ -30,7 +42,19 @@ pub(crate) fn complete_expr_snippet(
         add_custom_completions(acc, ctx, cap, SnippetScope::Expr);
     }
 
+    if in_array_len {
+        // Anything beyond a plain path needs to be wrapped in a const block, e.g. `[u8; { N + 1 }]`.
+        snippet(ctx, cap, "{ … }", "{ $0 }").add_to(acc, ctx.db);
+    }
+
+    if is_format_args_template(&path_ctx.path) {
+        snippet(ctx, cap, "\"{}\", …", "\"{}\", $0").add_to(acc, ctx.db);
+    }
+
+    add_remaining_args_snippet(acc, ctx, cap, path_ctx);
+
     if in_block_expr {
+        snippet(ctx, cap, "let-else", "let $1 = $2 else { $0 };").add_to(acc, ctx.db);
         snippet(ctx, cap, "pd", "eprintln!(\"$0 = {:?}\", $0);").add_to(acc, ctx.db);
         snippet(ctx, cap, "ppd", "eprintln!(\"$0 = {:#?}\", $0);").add_to(acc, ctx.db);
         let item = snippet(
@@ -46,6 +70,80 @@ macro_rules! $1 {
         );
         item.add_to(acc, ctx.db);
     }
+
+    if !in_unsafe_context {
+        // Calling an `unsafe fn` requires an `unsafe` block here, so offer to wrap the call in one.
+        ctx.process_all_names(&mut |name, def, _| {
+            let ScopeDef::ModuleDef(hir::ModuleDef::Function(func)) = def else { return };
+            if !func.is_unsafe_to_call(ctx.db) {
+                return;
+            }
+            let name = name.display(ctx.db);
+            let (label, body) = if func.assoc_fn_params(ctx.db).is_empty() {
+                (format!("unsafe {{ {name}() }}"), format!("unsafe {{ {name}() }}$0"))
+            } else {
+                (format!("unsafe {{ {name}(…) }}"), format!("unsafe {{ {name}($0) }}"))
+            };
+            let mut item = snippet(ctx, cap, &label, &body);
+            item.lookup_by(format!("unsafe {name}"));
+            item.add_to(acc, ctx.db);
+        });
+    }
+}
+
+/// Completes the pattern of a `let` statement with an `Option` or `Result` initializer into a
+/// let-else statement destructuring the happy case, e.g. `let Some(val) = x else {};`.
+pub(crate) fn complete_let_else_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    pattern_ctx: &PatternContext,
+) -> Option<()> {
+    let cap = ctx.config.snippet_cap?;
+    if pattern_ctx.parent_pat.is_some()
+        || pattern_ctx.has_type_ascription
+        || pattern_ctx.ref_token.is_some()
+        || pattern_ctx.mut_token.is_some()
+    {
+        return None;
+    }
+    let let_stmt = ctx.original_token.parent_ancestors().find_map(ast::LetStmt::cast)?;
+    if let_stmt.let_else().is_some() {
+        return None;
+    }
+    let source_range = ctx.source_range();
+    // Only the top-level pattern between `let` and `=` is being written here.
+    if let_stmt.let_token()?.text_range().end() > source_range.start()
+        || let_stmt.eq_token()?.text_range().start() < source_range.end()
+    {
+        return None;
+    }
+    let initializer = let_stmt.initializer()?;
+    let ty = ctx.sema.type_of_expr(&initializer)?.original;
+    let try_enum = TryEnum::from_ty(&ctx.sema, &ty.strip_references())?;
+    let variant = try_enum.happy_case();
+
+    let binding = match ctx.original_token.kind() {
+        SyntaxKind::IDENT => ctx.original_token.text(),
+        _ => "",
+    };
+    let pattern = match binding {
+        "" => format!("{variant}($1)"),
+        binding => format!("{variant}(${{1:{binding}}})"),
+    };
+    // The initializer may span several lines, so the `else` goes into a separate edit without the
+    // cursor, leaving the main edit on the pattern.
+    let mut edit = TextEdit::builder();
+    edit.replace(source_range, pattern);
+    edit.insert(initializer.syntax().text_range().end(), " else {}".to_owned());
+    let mut item = CompletionItem::new(
+        CompletionItemKind::Snippet,
+        source_range,
+        format!("let-else {variant}"),
+    );
+    item.lookup_by(if binding.is_empty() { variant } else { binding })
+        .snippet_edit(cap, edit.finish());
+    item.add_to(acc, ctx.db);
+    Some(())
 }
 
 pub(crate) fn complete_item_snippet(
@@ -115,6 +213,258 @@ macro_rules! $1 {
         );
         item.add_to(acc, ctx.db);
     }
+    if let Some(adt) = preceding_non_generic_adt(path_ctx) {
+        add_impl_default_snippet(acc, ctx, cap, &adt);
+        add_impl_from_snippet(acc, ctx, cap, &adt);
+        add_impl_partial_eq_snippet(acc, ctx, cap, &adt);
+    }
+}
+
+/// Offers filling in all remaining arguments of a call at once, with placeholders named after
+/// the parameters, e.g. `${1:b}, ${2:c}` in `f(a, $0)` for `fn f(a: u32, b: u32, c: u32)`.
+fn add_remaining_args_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    cap: SnippetCap,
+    path_ctx: &PathCompletionCtx,
+) -> Option<()> {
+    let path_expr = path_ctx.path.syntax().parent().and_then(ast::PathExpr::cast)?;
+    let arg_list = path_expr.syntax().parent().and_then(ast::ArgList::cast)?;
+    let (callable, active_param) = callable_for_token(&ctx.sema, ctx.token.clone())?;
+    let active_param = active_param?;
+    // Only fill in trailing arguments, leaving any the user already wrote after the cursor alone.
+    if arg_list.args().count() > active_param + 1 {
+        return None;
+    }
+    let remaining: Vec<_> = callable
+        .params(ctx.db)
+        .into_iter()
+        .skip(active_param)
+        .map(|(pat, _)| match pat?.right()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.text().to_string()),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    if remaining.len() < 2 {
+        return None;
+    }
+
+    let label = remaining.join(", ");
+    let body = remaining
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| format!("${{{}:{name}}}", idx + 1))
+        .join(", ");
+    snippet(ctx, cap, &label, &body).add_to(acc, ctx.db);
+    Some(())
+}
+
+/// Whether `path` is the format string of a `format_args!` invocation, which all of `format!`,
+/// `write!`, `println!` etc. expand to.
+fn is_format_args_template(path: &ast::Path) -> bool {
+    let Some(expr) = path.syntax().parent().and_then(ast::PathExpr::cast) else { return false };
+    expr.syntax()
+        .parent()
+        .and_then(ast::FormatArgsExpr::cast)
+        .and_then(|format_args| format_args.template())
+        .map_or(false, |template| template.syntax() == expr.syntax())
+}
+
+/// The type defined right before the item being completed, if it has no generic parameters.
+fn preceding_non_generic_adt(path_ctx: &PathCompletionCtx) -> Option<ast::Adt> {
+    let item = path_ctx.path.syntax().ancestors().find_map(ast::Item::cast)?;
+    let adt = item.syntax().siblings(Direction::Prev).skip(1).find_map(ast::Adt::cast)?;
+    adt.generic_param_list().is_none().then_some(adt)
+}
+
+/// Offers an `impl From` scaffold for the type defined right before the completion position.
+fn add_impl_from_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    cap: SnippetCap,
+    adt: &ast::Adt,
+) -> Option<()> {
+    ctx.famous_defs().core_convert_From()?;
+    let name = adt.name()?;
+
+    let label = format!("impl From<…> for {name}");
+    let body = format!(
+        "\
+impl From<$1> for {name} {{
+    fn from(value: $1) -> Self {{
+        $0
+    }}
+}}"
+    );
+    snippet(ctx, cap, &label, &body).add_to(acc, ctx.db);
+    Some(())
+}
+
+/// Offers an `impl PartialEq` scaffold for the type defined right before the completion position
+/// if it derives `Hash` but not `PartialEq`, reminding that the two have to agree.
+fn add_impl_partial_eq_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    cap: SnippetCap,
+    adt: &ast::Adt,
+) -> Option<()> {
+    let derives: Vec<_> = adt
+        .attrs()
+        .filter(|attr| attr.simple_name().as_deref() == Some("derive"))
+        .filter_map(|attr| attr.meta()?.token_tree())
+        .flat_map(|tt| {
+            tt.syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|it| it.kind() == SyntaxKind::IDENT)
+                .map(|it| it.text().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let derives = |name: &str| derives.iter().any(|it| it == name);
+    if !derives("Hash") || derives("PartialEq") {
+        return None;
+    }
+    let name = adt.name()?;
+
+    let label = format!("impl PartialEq for {name}");
+    let body = format!(
+        "\
+impl PartialEq for {name} {{
+    fn eq(&self, other: &Self) -> bool {{
+        $0
+    }}
+}}"
+    );
+    snippet(ctx, cap, &label, &body).add_to(acc, ctx.db);
+    Some(())
+}
+
+/// Offers an `impl Default` scaffold for the struct defined right before the completion position
+/// if it doesn't implement `Default` yet.
+fn add_impl_default_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    cap: SnippetCap,
+    adt: &ast::Adt,
+) -> Option<()> {
+    let default_trait = ctx.famous_defs().core_default_Default()?;
+    let ast::Adt::Struct(_) = adt else { return None };
+    let name = adt.name()?;
+    let strukt = ctx.module.declarations(ctx.db).into_iter().find_map(|def| match def {
+        hir::ModuleDef::Adt(hir::Adt::Struct(strukt))
+            if strukt.name(ctx.db).to_smol_str() == name.text().as_str() =>
+        {
+            Some(strukt)
+        }
+        _ => None,
+    })?;
+    if strukt.ty(ctx.db).impls_trait(ctx.db, default_trait, &[]) {
+        return None;
+    }
+    let self_expr = match strukt.kind(ctx.db) {
+        hir::StructKind::Record => "Self { $0 }",
+        hir::StructKind::Tuple => "Self($0)",
+        hir::StructKind::Unit => "Self$0",
+    };
+
+    let label = format!("impl Default for {name}");
+    let body = format!(
+        "\
+impl Default for {name} {{
+    fn default() -> Self {{
+        {self_expr}
+    }}
+}}"
+    );
+    snippet(ctx, cap, &label, &body).add_to(acc, ctx.db);
+    Some(())
+}
+
+/// Offers an `Iterator<Item = $0>` scaffold for `impl Trait` return types, e.g. `-> impl $0`.
+pub(crate) fn complete_impl_trait_return_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) {
+    if !path_ctx.is_trivial_path() {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let in_ret_impl_trait = path_ctx
+        .path
+        .syntax()
+        .ancestors()
+        .find_map(ast::ImplTraitType::cast)
+        .and_then(|it| it.syntax().parent())
+        .map_or(false, |it| ast::RetType::can_cast(it.kind()));
+    if !in_ret_impl_trait || ctx.famous_defs().core_iter_Iterator().is_none() {
+        return;
+    }
+
+    snippet(ctx, cap, "Iterator<Item = …>", "Iterator<Item = $0>").add_to(acc, ctx.db);
+}
+
+/// Offers bounds commonly taken by `impl Trait` arguments, e.g. `fn f(x: impl $0)`.
+pub(crate) fn complete_impl_trait_param_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) {
+    if !path_ctx.is_trivial_path() {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let in_param_impl_trait = path_ctx
+        .path
+        .syntax()
+        .ancestors()
+        .find_map(ast::ImplTraitType::cast)
+        .and_then(|it| it.syntax().parent())
+        .map_or(false, |it| ast::Param::can_cast(it.kind()));
+    if !in_param_impl_trait {
+        return;
+    }
+
+    if ctx.famous_defs().core_convert_AsRef().is_some() {
+        snippet(ctx, cap, "AsRef<str>", "AsRef<str>").add_to(acc, ctx.db);
+    }
+    if ctx.famous_defs().core_iter_IntoIterator().is_some() {
+        snippet(ctx, cap, "IntoIterator<Item = …>", "IntoIterator<Item = $0>").add_to(acc, ctx.db);
+    }
+}
+
+/// Offers a `Result<(), Box<dyn Error>>` return type for `#[test]` functions, which lets
+/// their bodies use `?`, e.g. `#[test] fn it_works() -> $0`.
+pub(crate) fn complete_test_result_return_snippet(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    ascription: &TypeAscriptionTarget,
+) {
+    if !path_ctx.is_trivial_path() || !matches!(ascription, TypeAscriptionTarget::RetType(_)) {
+        return;
+    }
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let is_test_fn =
+        path_ctx.path.syntax().ancestors().find_map(ast::Fn::cast).map_or(false, |it| {
+            it.attrs().any(|attr| attr.simple_name().as_deref() == Some("test"))
+        });
+    if !is_test_fn || ctx.famous_defs().std().is_none() {
+        return;
+    }
+
+    snippet(ctx, cap, "Result<(), Box<dyn Error>>", "Result<(), Box<dyn std::error::Error>>")
+        .add_to(acc, ctx.db);
 }
 
 fn snippet(ctx: &CompletionContext<'_>, cap: SnippetCap, label: &str, snippet: &str) -> Builder {
@@ -154,10 +504,251 @@ fn add_custom_completions(
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests::{check_edit_with_config, TEST_CONFIG},
+        tests::{check_edit, check_edit_with_config, get_all_items, TEST_CONFIG},
         CompletionConfig, Snippet,
     };
 
+    #[test]
+    fn format_string_scaffold() {
+        check_edit(
+            "\"{}\", …",
+            r#"
+//- minicore: fmt
+macro_rules! format {
+    ($($arg:tt)*) => (format_args!($($arg)*));
+}
+fn main() {
+    let value = 1;
+    format!($0);
+}
+"#,
+            r#"
+macro_rules! format {
+    ($($arg:tt)*) => (format_args!($($arg)*));
+}
+fn main() {
+    let value = 1;
+    format!("{}", $0);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn impl_iterator_return_scaffold() {
+        check_edit(
+            "Iterator<Item = …>",
+            r#"
+//- minicore: iterator
+struct Evens;
+fn evens() -> impl $0 {
+    Evens
+}
+"#,
+            r#"
+struct Evens;
+fn evens() -> impl Iterator<Item = $0> {
+    Evens
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_fn_result_return_scaffold() {
+        check_edit(
+            "Result<(), Box<dyn Error>>",
+            r#"
+//- /main.rs crate:main deps:std
+#[test]
+fn it_works() -> $0 {
+    Ok(())
+}
+//- /std.rs crate:std
+pub mod error {
+    pub trait Error {}
+}
+"#,
+            r#"
+#[test]
+fn it_works() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn impl_from_scaffold_after_type() {
+        check_edit(
+            "impl From<…> for Wrapper",
+            r#"
+//- minicore: from
+struct Wrapper(u32);
+$0
+"#,
+            r#"
+struct Wrapper(u32);
+impl From<$1> for Wrapper {
+    fn from(value: $1) -> Self {
+        $0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn impl_partial_eq_reminder_after_hash_derive() {
+        check_edit(
+            "impl PartialEq for Id",
+            r#"
+//- minicore: derive, hash
+#[derive(Hash)]
+struct Id(u32);
+$0
+"#,
+            r#"
+#[derive(Hash)]
+struct Id(u32);
+impl PartialEq for Id {
+    fn eq(&self, other: &Self) -> bool {
+        $0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_remaining_call_arguments() {
+        check_edit(
+            "b, c",
+            r#"
+fn f(a: u32, b: u32, c: u32) {}
+fn main() {
+    f(1, $0)
+}
+"#,
+            r#"
+fn f(a: u32, b: u32, c: u32) {}
+fn main() {
+    f(1, ${1:b}, ${2:c})
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn let_else_statement() {
+        check_edit(
+            "let-else",
+            r#"
+fn main() {
+    $0
+}
+"#,
+            r#"
+fn main() {
+    let $1 = $2 else { $0 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn let_else_prefills_option_pattern() {
+        check_edit(
+            "Some",
+            r#"
+//- minicore: option
+fn maybe() -> Option<u32> { None }
+fn main() {
+    let $0 = maybe();
+}
+"#,
+            r#"
+fn maybe() -> Option<u32> { None }
+fn main() {
+    let Some($1) = maybe() else {};
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn let_else_keeps_typed_binding() {
+        check_edit(
+            "val",
+            r#"
+//- minicore: option
+fn maybe() -> Option<u32> { None }
+fn main() {
+    let val$0 = maybe()
+        .and(maybe());
+}
+"#,
+            r#"
+fn maybe() -> Option<u32> { None }
+fn main() {
+    let Some(${1:val}) = maybe()
+        .and(maybe()) else {};
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn let_else_matches_initializer_type() {
+        let completions = |ra_fixture| {
+            get_all_items(TEST_CONFIG, ra_fixture, None)
+                .into_iter()
+                .map(|item| item.label.to_string())
+                .filter(|label| label.starts_with("let-else"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            completions(
+                r#"
+//- minicore: option, result
+fn fallible() -> Result<u32, ()> { Ok(0) }
+fn main() {
+    let x$0 = fallible();
+}
+"#
+            ),
+            ["let-else Ok"]
+        );
+        assert!(completions(
+            r#"
+//- minicore: option, result
+fn main() {
+    let x$0 = 92;
+}
+"#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn unsafe_block_for_unsafe_fn_call() {
+        check_edit(
+            "unsafe dangerous",
+            r#"
+unsafe fn dangerous(x: u32) {}
+fn main() {
+    dang$0
+}
+"#,
+            r#"
+unsafe fn dangerous(x: u32) {}
+fn main() {
+    unsafe { dangerous($0) }
+}
+"#,
+        );
+    }
+
     #[test]
     fn custom_snippet_completion() {
         check_edit_with_config(
@@ -1,12 +1,17 @@
 //! Completion of names from the current scope in type position.
 
-use hir::{HirDisplay, ScopeDef};
-use syntax::{ast, AstNode};
+use std::iter;
+
+use hir::{known, HirDisplay, ScopeDef};
+use ide_db::{FxHashSet, SymbolKind};
+use itertools::Itertools;
+use syntax::{ast, AstNode, TextRange};
+use text_edit::TextEdit;
 
 use crate::{
     context::{PathCompletionCtx, Qualified, TypeAscriptionTarget, TypeLocation},
     render::render_type_inference,
-    CompletionContext, Completions,
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionRelevance, Completions,
 };
 
 pub(crate) fn complete_type_path(
@@ -137,9 +142,27 @@ pub(crate) fn complete_type_path(
         }
         Qualified::Absolute => acc.add_crate_roots(ctx, path_ctx),
         Qualified::No => {
+            if complete_dyn_trait_lifetime_bounds(acc, ctx) {
+                return;
+            }
             match location {
                 TypeLocation::TypeBound => {
+                    if complete_lifetime_outlives_bounds(acc, ctx, path_ctx) {
+                        return;
+                    }
                     acc.add_nameref_keywords_with_colon(ctx);
+                    if can_start_higher_ranked_bound(path_ctx) {
+                        acc.add_keyword_snippet(ctx, "for<'a>", "for<'${1:a}> $0");
+                    }
+                    let usage = bounded_param_usage(path_ctx).unwrap_or_default();
+                    let fn_traits = [
+                        ctx.famous_defs().core_ops_Fn(),
+                        ctx.famous_defs().core_ops_FnMut(),
+                        ctx.famous_defs().core_ops_FnOnce(),
+                    ];
+                    if let Some(arity) = usage.call_arity {
+                        add_fn_bound_scaffold(acc, ctx, arity);
+                    }
                     ctx.process_all_names(&mut |name, res, doc_aliases| {
                         let add_resolution = match res {
                             ScopeDef::ModuleDef(hir::ModuleDef::Macro(mac)) => {
@@ -150,7 +173,26 @@ pub(crate) fn complete_type_path(
                             ) => true,
                             _ => false,
                         };
-                        if add_resolution {
+                        if !add_resolution {
+                            return;
+                        }
+                        let is_implied = match res {
+                            ScopeDef::ModuleDef(hir::ModuleDef::Trait(trait_)) => {
+                                let is_called_fn_trait =
+                                    usage.call_arity.is_some() && fn_traits.contains(&Some(trait_));
+                                is_called_fn_trait
+                                    || trait_.items(ctx.db).into_iter().any(|it| match it {
+                                        hir::AssocItem::Function(func) => usage
+                                            .called_methods
+                                            .contains(func.name(ctx.db).to_smol_str().as_str()),
+                                        _ => false,
+                                    })
+                            }
+                            _ => false,
+                        };
+                        if is_implied {
+                            acc.add_implied_bound(ctx, path_ctx, name, res, doc_aliases);
+                        } else {
                             acc.add_path_resolution(ctx, path_ctx, name, res, doc_aliases);
                         }
                     });
@@ -184,6 +226,10 @@ pub(crate) fn complete_type_path(
                         }
                     }
                 }
+                TypeLocation::ImplTarget => {
+                    complete_generic_impl_targets(acc, ctx, path_ctx);
+                    complete_ref_impl_targets(acc, ctx, path_ctx);
+                }
                 _ => {}
             };
 
@@ -197,6 +243,270 @@ pub(crate) fn complete_type_path(
     }
 }
 
+/// How the enclosing function uses its parameters of the bounded type parameter.
+#[derive(Default)]
+struct BoundedParamUsage {
+    /// The names of the methods called on them, e.g. `clone` in
+    /// `fn f<T: $0>(it: T) { it.clone(); }`.
+    called_methods: FxHashSet<String>,
+    /// The argument count of a call of one of them, e.g. `1` in `fn f<F: $0>(f: F) { f(0); }`.
+    call_arity: Option<usize>,
+}
+
+fn bounded_param_usage(path_ctx: &PathCompletionCtx) -> Option<BoundedParamUsage> {
+    let bounds = path_ctx.path.syntax().ancestors().find_map(ast::TypeBoundList::cast)?;
+    let parent = bounds.syntax().parent()?;
+    let param = match ast::WherePred::cast(parent.clone()) {
+        Some(pred) => match pred.ty()? {
+            ast::Type::PathType(it) => it.path()?.as_single_name_ref()?.text().to_string(),
+            _ => return None,
+        },
+        None => ast::TypeParam::cast(parent)?.name()?.text().to_string(),
+    };
+    let func = bounds.syntax().ancestors().find_map(ast::Fn::cast)?;
+
+    let is_of_param_ty = |ty: ast::Type| {
+        let ty = match ty {
+            ast::Type::RefType(it) => it.ty(),
+            it => Some(it),
+        };
+        match ty {
+            Some(ast::Type::PathType(it)) => it
+                .path()
+                .and_then(|it| it.as_single_name_ref())
+                .map_or(false, |it| it.text() == param),
+            _ => false,
+        }
+    };
+    let values: FxHashSet<String> = func
+        .param_list()?
+        .params()
+        .filter(|it| it.ty().map_or(false, is_of_param_ty))
+        .filter_map(|it| match it.pat()? {
+            ast::Pat::IdentPat(it) => Some(it.name()?.text().to_string()),
+            _ => None,
+        })
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    let is_param_value = |expr: Option<ast::Expr>| match expr {
+        Some(ast::Expr::PathExpr(it)) => it
+            .path()
+            .and_then(|it| it.as_single_name_ref())
+            .map_or(false, |it| values.contains(it.text().as_str())),
+        _ => false,
+    };
+
+    let body = func.body()?;
+    let called_methods = body
+        .syntax()
+        .descendants()
+        .filter_map(ast::MethodCallExpr::cast)
+        .filter(|call| is_param_value(call.receiver()))
+        .filter_map(|call| Some(call.name_ref()?.text().to_string()))
+        .collect();
+    let call_arity = body
+        .syntax()
+        .descendants()
+        .filter_map(ast::CallExpr::cast)
+        .find(|call| is_param_value(call.expr()))
+        .and_then(|call| Some(call.arg_list()?.args().count()));
+    Some(BoundedParamUsage { called_methods, call_arity })
+}
+
+/// Offers a `Fn(…) -> …` bound scaffold taking as many arguments as the bounded type
+/// parameter is called with in the function body.
+fn add_fn_bound_scaffold(acc: &mut Completions, ctx: &CompletionContext<'_>, arity: usize) {
+    let Some(cap) = ctx.config.snippet_cap else { return };
+    let label = if arity == 0 { "Fn() -> …" } else { "Fn(…) -> …" };
+    let args = (1..=arity).map(|it| format!("${it}")).join(", ");
+    let mut item = CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), label);
+    item.insert_snippet(cap, format!("Fn({args}) -> $0"))
+        .set_relevance(CompletionRelevance { is_implied_bound: true, ..Default::default() });
+    item.add_to(acc, ctx.db);
+}
+
+/// Completes lifetime bounds continuing a trait object type, e.g. `+ 'static` in
+/// `Box<dyn Trait $0>`.
+fn complete_dyn_trait_lifetime_bounds(acc: &mut Completions, ctx: &CompletionContext<'_>) -> bool {
+    let source_start = ctx.source_range().start();
+    let Some(prev_token) = iter::successors(Some(ctx.original_token.clone()), |it| it.prev_token())
+        .find(|it| !it.kind().is_trivia() && it.text_range().end() <= source_start)
+    else {
+        return false;
+    };
+    let follows_dyn_trait = prev_token
+        .parent_ancestors()
+        .find_map(ast::DynTraitType::cast)
+        .map_or(false, |it| it.syntax().text_range().end() == prev_token.text_range().end());
+    if !follows_dyn_trait {
+        return false;
+    }
+
+    let mut add_bound = |lifetime: hir::Name| {
+        let label = format!("+ {}", lifetime.display(ctx.db));
+        CompletionItem::new(SymbolKind::LifetimeParam, ctx.source_range(), label)
+            .add_to(acc, ctx.db)
+    };
+    ctx.process_all_names_raw(&mut |name, res| {
+        if let ScopeDef::GenericParam(hir::GenericParam::LifetimeParam(_)) = res {
+            add_bound(name);
+        }
+    });
+    add_bound(known::STATIC_LIFETIME);
+    true
+}
+
+/// Completes local generic types as impl targets with their type parameters taken from the impl
+/// header, e.g. `Wrapper<T>` in `impl<T> Trait for $0`.
+fn complete_generic_impl_targets(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) {
+    if !path_ctx.is_trivial_path() {
+        return;
+    }
+    let header_params: Vec<_> = path_ctx
+        .path
+        .syntax()
+        .ancestors()
+        .find_map(ast::Impl::cast)
+        .and_then(|it| it.generic_param_list())
+        .into_iter()
+        .flat_map(|it| it.generic_params())
+        .filter_map(|it| match it {
+            ast::GenericParam::TypeParam(it) => it.name(),
+            _ => None,
+        })
+        .collect();
+    if header_params.is_empty() {
+        return;
+    }
+
+    ctx.process_all_names(&mut |name, def, _| {
+        let ScopeDef::ModuleDef(hir::ModuleDef::Adt(adt)) = def else { return };
+        if adt.module(ctx.db).krate() != ctx.krate {
+            return;
+        }
+        let params = hir::GenericDef::from(adt).params(ctx.db);
+        let only_type_params =
+            params.iter().all(|it| matches!(it, hir::GenericParam::TypeParam(_)));
+        if params.is_empty() || params.len() > header_params.len() || !only_type_params {
+            return;
+        }
+        let label = format!(
+            "{}<{}>",
+            name.display(ctx.db),
+            header_params.iter().take(params.len()).format(", ")
+        );
+        CompletionItem::new(adt_kind(adt), ctx.source_range(), label).add_to(acc, ctx.db);
+    });
+}
+
+/// Completes local types behind a reference without a lifetime as impl targets, introducing the
+/// lifetime in the impl header, e.g. `impl<'a> Trait for &'a Foo` for `impl Trait for &Fo$0`.
+fn complete_ref_impl_targets(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) -> Option<()> {
+    if !path_ctx.is_trivial_path() {
+        return None;
+    }
+    let ref_ty = ast::RefType::cast(path_ctx.path.syntax().parent()?.parent()?)?;
+    if ref_ty.lifetime().is_some() {
+        return None;
+    }
+    let impl_ = ref_ty.syntax().ancestors().find_map(ast::Impl::cast)?;
+    let (header_offset, header_text) = match impl_.generic_param_list() {
+        Some(list) if list.lifetime_params().next().is_some() => return None,
+        Some(list) if list.generic_params().next().is_some() => {
+            (list.l_angle_token()?.text_range().end(), "'a, ")
+        }
+        Some(list) => (list.l_angle_token()?.text_range().end(), "'a"),
+        None => (impl_.impl_token()?.text_range().end(), "<'a>"),
+    };
+    // Both the header and the reference precede the cursor, so their offsets are the same in
+    // the original file.
+    let target_range =
+        TextRange::new(ref_ty.syntax().text_range().start(), ctx.source_range().end());
+    let mut_ = if ref_ty.mut_token().is_some() { "mut " } else { "" };
+
+    ctx.process_all_names(&mut |name, def, _| {
+        let ScopeDef::ModuleDef(hir::ModuleDef::Adt(adt)) = def else { return };
+        if adt.module(ctx.db).krate() != ctx.krate
+            || !hir::GenericDef::from(adt).params(ctx.db).is_empty()
+        {
+            return;
+        }
+        let label = format!("&'a {mut_}{}", name.display(ctx.db));
+        let mut edit = TextEdit::builder();
+        edit.insert(header_offset, header_text.to_owned());
+        edit.replace(target_range, label.clone());
+        let mut item = CompletionItem::new(adt_kind(adt), ctx.source_range(), label);
+        item.text_edit(edit.finish());
+        item.add_to(acc, ctx.db);
+    });
+    Some(())
+}
+
+fn adt_kind(adt: hir::Adt) -> SymbolKind {
+    match adt {
+        hir::Adt::Struct(_) => SymbolKind::Struct,
+        hir::Adt::Union(_) => SymbolKind::Union,
+        hir::Adt::Enum(_) => SymbolKind::Enum,
+    }
+}
+
+/// Whether a `for<'a>` binder may start the bound at the cursor, e.g. `where F: $0`. Predicates
+/// that already have a binder, like `where for<'a> F: $0`, can't take another one.
+fn can_start_higher_ranked_bound(path_ctx: &PathCompletionCtx) -> bool {
+    if !path_ctx.is_trivial_path() {
+        return false;
+    }
+    let Some(bound_list) = path_ctx.path.syntax().ancestors().find_map(ast::TypeBoundList::cast)
+    else {
+        return false;
+    };
+    match bound_list.syntax().parent() {
+        Some(parent) => match ast::WherePred::cast(parent.clone()) {
+            Some(pred) => pred.ty().is_some() && pred.generic_param_list().is_none(),
+            None => ast::TypeParam::can_cast(parent.kind()),
+        },
+        None => false,
+    }
+}
+
+/// Completes the lifetimes a lifetime may be declared to outlive, e.g. `'b` in `where 'a: $0`.
+fn complete_lifetime_outlives_bounds(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) -> bool {
+    let Some(bounded) = path_ctx
+        .path
+        .syntax()
+        .ancestors()
+        .find_map(ast::TypeBoundList::cast)
+        .and_then(|it| ast::WherePred::cast(it.syntax().parent()?))
+        .and_then(|it| it.lifetime())
+    else {
+        return false;
+    };
+
+    ctx.process_all_names_raw(&mut |name, res| {
+        if let ScopeDef::GenericParam(hir::GenericParam::LifetimeParam(_)) = res {
+            if name.to_smol_str() != bounded.text().as_str() {
+                acc.add_lifetime(ctx, name);
+            }
+        }
+    });
+    acc.add_lifetime(ctx, known::STATIC_LIFETIME);
+    true
+}
+
 pub(crate) fn complete_ascribed_type(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -219,3 +529,21 @@ pub(crate) fn complete_ascribed_type(
     acc.add(render_type_inference(ty_string, ctx));
     None
 }
+
+/// Offers `_` as the target of `expr as $0` when the cast's type can be inferred from its
+/// surroundings.
+pub(crate) fn complete_inferred_cast_target(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+) -> Option<()> {
+    if !path_ctx.is_trivial_path() {
+        return None;
+    }
+    let expected = ctx.expected_type.as_ref()?;
+    if expected.is_unknown() {
+        return None;
+    }
+    acc.add(render_type_inference("_".to_owned(), ctx));
+    None
+}
@@ -2,7 +2,10 @@
 
 use hir::ScopeDef;
 use ide_db::{FxHashSet, SymbolKind};
-use syntax::{ast, AstNode};
+use syntax::{
+    ast::{self, HasVisibility},
+    AstNode,
+};
 
 use crate::{
     context::{CompletionContext, PathCompletionCtx, Qualified},
@@ -42,6 +45,23 @@ pub(crate) fn complete_use_path(
                 }
             }
 
+            // offer re-exporting everything from a module or enum, as in `pub use foo::*;`
+            let is_reexport = ctx
+                .token
+                .parent_ancestors()
+                .find_map(ast::Use::cast)
+                .map_or(false, |it| it.visibility().is_some());
+            if is_reexport
+                && matches!(
+                    resolution,
+                    hir::PathResolution::Def(
+                        hir::ModuleDef::Module(_) | hir::ModuleDef::Adt(hir::Adt::Enum(_))
+                    )
+                )
+            {
+                acc.add_keyword(ctx, "*");
+            }
+
             match resolution {
                 hir::PathResolution::Def(hir::ModuleDef::Module(module)) => {
                     let module_scope = module.scope(ctx.db, Some(ctx.module));
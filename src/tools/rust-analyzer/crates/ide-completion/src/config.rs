@@ -4,7 +4,10 @@
 //! module, and we use to statically check that we only produce snippet
 //! completions if we are allowed to.
 
-use ide_db::{imports::insert_use::InsertUseConfig, SnippetCap};
+use ide_db::{
+    imports::insert_use::{ImportInsertStrategy, InsertUseConfig},
+    SnippetCap,
+};
 
 use crate::snippet::Snippet;
 
@@ -14,14 +17,22 @@ pub struct CompletionConfig {
     pub enable_imports_on_the_fly: bool,
     pub enable_self_on_the_fly: bool,
     pub enable_private_editable: bool,
+    pub enable_keyword_completions: bool,
+    pub enable_derive_groups: bool,
+    pub enable_inherent_stubs: bool,
     pub full_function_signatures: bool,
+    pub override_comment: bool,
     pub callable: Option<CallableSnippets>,
+    pub call_paren_cursor: CallParenCursor,
+    pub matching_mode: CompletionMatchingMode,
     pub snippet_cap: Option<SnippetCap>,
     pub insert_use: InsertUseConfig,
+    pub import_insert_strategy: ImportInsertStrategy,
     pub prefer_no_std: bool,
     pub prefer_prelude: bool,
     pub snippets: Vec<Snippet>,
     pub limit: Option<usize>,
+    pub flyimport_max_results: Option<usize>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -30,6 +41,24 @@ pub enum CallableSnippets {
     AddParentheses,
 }
 
+/// Where to place the cursor after completing a call without arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallParenCursor {
+    /// `foo($0)`
+    Inside,
+    /// `foo()$0`
+    After,
+}
+
+/// How the names of offered completions have to match what has been typed so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionMatchingMode {
+    /// Offer all candidates, leaving the fuzzy matching to the client.
+    Fuzzy,
+    /// Only offer candidates whose name starts with what has been typed.
+    Prefix,
+}
+
 impl CompletionConfig {
     pub fn postfix_snippets(&self) -> impl Iterator<Item = (&str, &Snippet)> {
         self.snippets
@@ -24,7 +24,9 @@
 use text_edit::Indel;
 
 use crate::{
-    context::analysis::{expand_and_analyze, AnalysisResult},
+    context::analysis::{
+        expand_and_analyze, expected_impl_trait_bounds, is_in_result_error_position, AnalysisResult,
+    },
     CompletionConfig,
 };
 
@@ -67,7 +69,6 @@ pub(crate) struct PathCompletionCtx {
     pub(super) qualified: Qualified,
     /// The parent of the path we are completing.
     pub(super) parent: Option<ast::Path>,
-    #[allow(dead_code)]
     /// The path of which we are completing the segment
     pub(super) path: ast::Path,
     /// The path of which we are completing the segment in the original file
@@ -148,6 +149,10 @@ pub(crate) struct ExprCtx {
     /// Whether this expression occurs in match arm guard position: before the
     /// fat arrow token
     pub(crate) in_match_guard: bool,
+    /// Whether this expression is inside an `unsafe` block or `unsafe fn`
+    pub(crate) in_unsafe_context: bool,
+    /// Whether this expression is the length of an array type, e.g. `[u8; $0]`
+    pub(crate) in_array_len: bool,
 }
 
 /// Original file ast nodes
@@ -169,6 +174,8 @@ pub(crate) enum TypeLocation {
     /// Associated constant equality constraint e.g. `Foo<X = $0>`
     AssocConstEq,
     TypeBound,
+    /// Target type of a cast expression e.g. `expr as $0`
+    CastTarget,
     ImplTarget,
     ImplTrait,
     Other,
@@ -372,6 +379,9 @@ pub(super) enum CompletionAnalysis {
         colon_prefix: bool,
         fake_attribute_under_caret: Option<ast::Attr>,
     },
+    /// Set if we are currently completing the fragment specifier of a `macro_rules!` metavariable,
+    /// like `$x:$0`
+    MacroFragmentSpecifier,
 }
 
 /// Information about the field or method access we are completing.
@@ -426,10 +436,17 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) expected_name: Option<NameOrNameRef>,
     /// The expected type of what we are completing.
     pub(super) expected_type: Option<Type>,
+    /// The trait bounds of the `impl Trait` parameter whose argument we are completing.
+    pub(super) expected_impl_traits: Vec<hir::Trait>,
+    /// The `Error` trait if we are completing the error type argument of a `Result`, as any error
+    /// type fits there.
+    pub(super) expected_error_trait: Option<hir::Trait>,
 
     pub(super) qualifier_ctx: QualifierCtx,
 
     pub(super) locals: FxHashMap<Name, Local>,
+    /// The items of the standard prelude by name, to tell when a completion shadows one of them.
+    pub(super) prelude_items: FxHashMap<Name, Vec<ScopeDef>>,
 
     /// The module depth of the current module of the cursor position.
     /// - crate-root
@@ -455,6 +472,15 @@ pub(crate) fn source_range(&self) -> TextRange {
         }
     }
 
+    /// The part of the identifier being completed that precedes the cursor.
+    pub(crate) fn typed_prefix(&self) -> &str {
+        let range = self.original_token.text_range();
+        if self.source_range() != range || !range.contains_inclusive(self.position.offset) {
+            return "";
+        }
+        &self.original_token.text()[..usize::from(self.position.offset - range.start())]
+    }
+
     pub(crate) fn famous_defs(&self) -> FamousDefs<'_, '_> {
         FamousDefs(&self.sema, self.krate)
     }
@@ -705,7 +731,20 @@ pub(super) fn new(
             }
         });
 
+        let mut prelude_items: FxHashMap<_, Vec<_>> = FxHashMap::default();
+        let famous_defs = FamousDefs(&sema, krate);
+        if let Some(prelude) =
+            famous_defs.std_prelude_v1().or_else(|| famous_defs.core_prelude_v1())
+        {
+            for (name, def) in prelude.scope(db, None) {
+                prelude_items.entry(name).or_default().push(def);
+            }
+        }
+
         let depth_from_crate_root = iter::successors(module.parent(db), |m| m.parent(db)).count();
+        let expected_impl_traits = expected_impl_trait_bounds(&sema, &token);
+        let expected_error_trait =
+            is_in_result_error_position(&token).then(|| famous_defs.core_error_Error()).flatten();
 
         let ctx = CompletionContext {
             sema,
@@ -720,8 +759,11 @@ pub(super) fn new(
             is_nightly,
             expected_name,
             expected_type,
+            expected_impl_traits,
+            expected_error_trait,
             qualifier_ctx,
             locals,
+            prelude_items,
             depth_from_crate_root,
         };
         Some((ctx, analysis))
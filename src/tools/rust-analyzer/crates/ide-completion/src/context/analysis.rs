@@ -2,7 +2,11 @@
 use std::iter;
 
 use hir::{HasSource, Semantics, Type, TypeInfo, Variant};
-use ide_db::{active_parameter::ActiveParameter, RootDatabase};
+use ide_db::{
+    active_parameter::{callable_for_token, ActiveParameter},
+    ty_filter::TryEnum,
+    RootDatabase,
+};
 use syntax::{
     algo::{find_node_at_offset, non_trivia_sibling},
     ast::{self, AttrKind, HasArgList, HasGenericParams, HasLoopBody, HasName, NameOrNameRef},
@@ -244,6 +248,8 @@ fn analyze(
     let Some(name_like) = find_node_at_offset(&speculative_file, offset) else {
         let analysis = if let Some(original) = ast::String::cast(original_token.clone()) {
             CompletionAnalysis::String { original, expanded: ast::String::cast(self_token.clone()) }
+        } else if is_macro_rules_fragment_specifier(&fake_ident_token) {
+            CompletionAnalysis::MacroFragmentSpecifier
         } else {
             // Fix up trailing whitespace problem
             // #[attr(foo = $0
@@ -304,6 +310,37 @@ fn analyze(
     Some((analysis, expected, qual_ctx))
 }
 
+/// Calculate the trait bounds of the `impl Trait` parameter the argument at the cursor is passed
+/// to, e.g. `Display` for `fn show(it: impl Display)` in `show($0)`.
+///
+/// Only traits without generic parameters besides `Self` are returned, so that callers can check
+/// them with `Type::impls_trait` alone.
+pub(super) fn expected_impl_trait_bounds(
+    sema: &Semantics<'_, RootDatabase>,
+    token: &SyntaxToken,
+) -> Vec<hir::Trait> {
+    (|| {
+        let (callable, idx) = callable_for_token(sema, token.clone())?;
+        let hir::CallableKind::Function(func) = callable.kind() else { return None };
+        // Method calls don't pass the receiver in the argument list.
+        let params = match func.assoc_fn_params(sema.db) {
+            params if params.len() == callable.n_params() => params,
+            _ => func.params_without_self(sema.db),
+        };
+        let param = params.get(idx?)?.ty().as_type_param(sema.db)?;
+        if !param.is_implicit(sema.db) {
+            return None;
+        }
+        let bounds = param
+            .trait_bounds(sema.db)
+            .into_iter()
+            .filter(|it| it.type_or_const_param_count(sema.db, false) == 0)
+            .collect();
+        Some(bounds)
+    })()
+    .unwrap_or_default()
+}
+
 /// Calculate the expected type and name of the cursor position.
 fn expected_type_and_name(
     sema: &Semantics<'_, RootDatabase>,
@@ -470,6 +507,18 @@ fn expected_type_and_name(
                         .map(|c| (Some(c.return_type()), None))
                         .unwrap_or((None, None))
                 },
+                ast::GenericArgList(it) => {
+                    match expected_result_error_type(sema, token, &it) {
+                        Some(ty) => (Some(ty), None),
+                        None => match node.parent() {
+                            Some(n) => {
+                                node = n;
+                                continue;
+                            },
+                            None => (None, None),
+                        },
+                    }
+                },
                 ast::ParamList(_) => (None, None),
                 ast::Stmt(_) => (None, None),
                 ast::Item(_) => (None, None),
@@ -488,6 +537,48 @@ fn expected_type_and_name(
     (ty.map(strip_refs), name)
 }
 
+/// Whether `token` is in the error position of a `Result<T, E>` generic argument list.
+pub(super) fn is_in_result_error_position(token: &SyntaxToken) -> bool {
+    token
+        .parent_ancestors()
+        .find_map(ast::GenericArgList::cast)
+        .map_or(false, |arg_list| is_result_error_arg(token, &arg_list))
+}
+
+fn is_result_error_arg(token: &SyntaxToken, arg_list: &ast::GenericArgList) -> bool {
+    let is_result = arg_list
+        .syntax()
+        .parent()
+        .and_then(ast::PathSegment::cast)
+        .and_then(|segment| segment.name_ref())
+        .map_or(false, |name_ref| name_ref.text() == "Result");
+    let arg_idx = arg_list
+        .generic_args()
+        .take_while(|arg| arg.syntax().text_range().end() <= token.text_range().start())
+        .count();
+    is_result && arg_idx == 1
+}
+
+/// The error type of the `Result` returned by the enclosing function, if `token` is in the error
+/// position of a `Result<T, E>` generic argument list.
+fn expected_result_error_type(
+    sema: &Semantics<'_, RootDatabase>,
+    token: &SyntaxToken,
+    arg_list: &ast::GenericArgList,
+) -> Option<Type> {
+    if !is_result_error_arg(token, arg_list) {
+        return None;
+    }
+
+    let func = arg_list.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let ret_ty = sema.to_def(&func)?.ret_type(sema.db);
+    if !matches!(TryEnum::from_ty(sema, &ret_ty)?, TryEnum::Result) {
+        return None;
+    }
+    let error_ty = ret_ty.type_arguments().nth(1)?;
+    (!error_ty.is_unknown()).then_some(error_ty)
+}
+
 fn classify_lifetime(
     _sema: &Semantics<'_, RootDatabase>,
     original_file: &SyntaxNode,
@@ -915,6 +1006,7 @@ fn classify_name_ref(
                     TypeLocation::GenericArg { args, of_trait: None, corresponding_param: None }
                 },
                 ast::TupleField(_) => TypeLocation::TupleField,
+                ast::CastExpr(_) => TypeLocation::CastTarget,
                 _ => return None,
             }
         };
@@ -1002,6 +1094,12 @@ fn classify_name_ref(
                 .map_or(true, |arrow| it.text_range().start() < arrow.text_range().start()),
             None => false,
         };
+        let in_unsafe_context = is_in_unsafe_context(it);
+        let in_array_len = it
+            .parent()
+            .and_then(ast::ConstArg::cast)
+            .and_then(|it| it.syntax().parent())
+            .map_or(false, |it| ast::ArrayType::can_cast(it.kind()));
 
         PathKind::Expr {
             expr_ctx: ExprCtx {
@@ -1016,6 +1114,8 @@ fn classify_name_ref(
                 incomplete_let,
                 impl_,
                 in_match_guard,
+                in_unsafe_context,
+                in_array_len,
             },
         }
     };
@@ -1495,6 +1595,47 @@ fn is_in_loop_body(node: &SyntaxNode) -> bool {
         .is_some()
 }
 
+fn is_in_unsafe_context(node: &SyntaxNode) -> bool {
+    node.ancestors()
+        .find_map(|it| {
+            match_ast! {
+                match it {
+                    ast::BlockExpr(it) => it.unsafe_token().map(|_| true),
+                    ast::Fn(it) => Some(it.unsafe_token().is_some()),
+                    _ => ast::Item::can_cast(it.kind()).then_some(false),
+                }
+            }
+        })
+        .unwrap_or(false)
+}
+
+/// Checks whether the token is the fragment specifier of a metavariable in a `macro_rules!`
+/// matcher, like `($x:$0) => {}`.
+fn is_macro_rules_fragment_specifier(token: &SyntaxToken) -> bool {
+    let follows_metavar = (|| {
+        let colon = previous_non_trivia_token(token.clone())?;
+        let metavar = previous_non_trivia_token(colon.clone())?;
+        let dollar = previous_non_trivia_token(metavar.clone())?;
+        Some(
+            colon.kind() == T![:]
+                && (metavar.kind() == SyntaxKind::IDENT || metavar.kind().is_keyword())
+                && dollar.kind() == T![$],
+        )
+    })();
+    if follows_metavar != Some(true) {
+        return false;
+    }
+    token.parent_ancestors().take_while(|it| it.kind() == SyntaxKind::TOKEN_TREE).any(|tt| {
+        let is_rule = tt
+            .parent()
+            .and_then(|body| body.parent())
+            .map_or(false, |it| it.kind() == SyntaxKind::MACRO_RULES);
+        is_rule
+            && non_trivia_sibling(tt.into(), Direction::Next)
+                .map_or(false, |it| it.kind() == T![=>])
+    })
+}
+
 fn previous_non_trivia_token(e: impl Into<SyntaxElement>) -> Option<SyntaxToken> {
     let mut token = match e.into() {
         SyntaxElement::Node(n) => n.first_token()?,
@@ -66,6 +66,10 @@ pub struct CompletionItem {
     /// after completion.
     pub trigger_call_info: bool,
 
+    /// Whether this item is a method provided by a trait rather than by an inherent impl, so
+    /// that editors can style the two differently.
+    pub is_trait_method: bool,
+
     /// We use this to sort completion. Relevance records facts like "do the
     /// types align precisely?". We can't sort by relevances directly, they are
     /// only partially ordered.
@@ -123,6 +127,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.trigger_call_info {
             s.field("trigger_call_info", &true);
         }
+        if self.is_trait_method {
+            s.field("is_trait_method", &true);
+        }
         s.finish()
     }
 }
@@ -139,6 +146,16 @@ pub struct CompletionRelevance {
     /// }
     /// ```
     pub exact_name_match: bool,
+    /// This is set when the name of a local loosely matches the name of the expected param:
+    ///
+    /// ```
+    /// fn sleep(timeout: u64) {}
+    /// fn main {
+    ///     let request_timeout = 92;
+    ///     sleep($0) // `request_timeout` contains the name of the param
+    /// }
+    /// ```
+    pub fuzzy_name_match: bool,
     /// See CompletionRelevanceTypeMatch doc comments for cases where this is set.
     pub type_match: Option<CompletionRelevanceTypeMatch>,
     /// This is set in cases like these:
@@ -164,6 +181,110 @@ pub struct CompletionRelevance {
     pub postfix_match: Option<CompletionRelevancePostfixMatch>,
     /// This is set for type inference results
     pub is_definite: bool,
+    /// This is set for `?`-propagating call completions whose error type converts into the
+    /// error type of the enclosing function:
+    ///
+    /// ```
+    /// fn bar() -> Result<(), Error> {
+    ///     foo()?$0 // `foo` returns `Result<_, OtherError>` and `Error: From<OtherError>`
+    /// }
+    /// ```
+    pub is_try_convertible: bool,
+    /// This is set for methods returning the type of their receiver in positions that don't
+    /// expect a particular type, as these are likely to be chained:
+    ///
+    /// ```
+    /// fn main() {
+    ///     Builder::default().$0 // `fn name(self, name: &str) -> Self`
+    /// }
+    /// ```
+    pub returns_receiver_type: bool,
+    /// This is set for items that share their name with, but are distinct from, an item of the
+    /// prelude. It does not affect the score, but lets clients point out the shadowing:
+    ///
+    /// ```
+    /// type Result<T> = core::result::Result<T, Error>;
+    /// fn main() {
+    ///     Res$0 // the local alias shadows the prelude's `Result`
+    /// }
+    /// ```
+    pub shadows_prelude_item: bool,
+    /// This is set for traits completed as a bound of a type parameter whose values already use
+    /// one of the trait's methods in the function body:
+    ///
+    /// ```
+    /// fn dup<T>(it: T) -> (T, T)
+    /// where
+    ///     T: $0 // `Clone`, as `it.clone()` is called below
+    /// {
+    ///     (it.clone(), it)
+    /// }
+    /// ```
+    pub is_implied_bound: bool,
+    /// Set for well-known items like the standard derives, ranking them by how commonly they
+    /// are used, `0` being the most common.
+    pub popularity_rank: Option<u8>,
+    /// This is set for items whose documentation summary mentions what has been typed so far,
+    /// weighing as much as the other minor signals like `is_local`:
+    ///
+    /// ```
+    /// /// Parses the input.
+    /// fn run() {}
+    /// fn main() {
+    ///     pars$0 // `run` is documented as parsing
+    /// }
+    /// ```
+    pub doc_mentions_prefix: bool,
+    /// This is set for items that aren't visible from the current module, which are only
+    /// completed with private editable completions enabled, ranking them below visible items:
+    ///
+    /// ```
+    /// mod m { fn internal() {} pub(crate) fn api() {} }
+    /// fn main() {
+    ///     m::$0 // `api` ranks above `internal`
+    /// }
+    /// ```
+    pub is_private_item: bool,
+    /// This is set for methods of a generic receiver that are provided by the bounds of its type
+    /// parameter, rather than by incidental blanket implementations:
+    ///
+    /// ```
+    /// fn dup<T: Clone>(it: T) {
+    ///     it.$0 // `clone` comes from the `T: Clone` bound
+    /// }
+    /// ```
+    pub is_bound_method: bool,
+    /// This is set for getters named like a field of their receiver that isn't accessible at the
+    /// cursor, as calling them is how the field can be read:
+    ///
+    /// ```
+    /// mod m {
+    ///     pub struct User { name: String }
+    ///     impl User { pub fn name(&self) -> &str { &self.name } }
+    /// }
+    /// fn greet(user: m::User) {
+    ///     user.$0 // `name()` stands in for the private `name` field
+    /// }
+    /// ```
+    pub is_private_field_getter: bool,
+    /// This is set for `?`-propagating call completions in the tail position of a function,
+    /// where the `?` would have the value wrapped up again right away:
+    ///
+    /// ```
+    /// fn bar() -> Result<u32, Error> {
+    ///     foo()?$0 // returning `foo()` as is is what's usually wanted
+    /// }
+    /// ```
+    pub is_redundant_try: bool,
+    /// This is set for items that the surrounding code makes the likely choice among their
+    /// siblings, without them matching the expected type any better:
+    ///
+    /// ```
+    /// fn main() {
+    ///     read().unwr$0 // `unwrap_or_else`, as `?` is not available in `main`
+    /// }
+    /// ```
+    pub is_suggested_by_context: bool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -181,6 +302,16 @@ pub enum CompletionRelevanceTypeMatch {
     /// This is set in cases like these:
     ///
     /// ```
+    /// fn f(spam: &String) {}
+    /// fn main {
+    ///     let foo = String::new();
+    ///     f($0) // type of local matches the type of param once referenced, as in `&foo`
+    /// }
+    /// ```
+    Coerced,
+    /// This is set in cases like these:
+    ///
+    /// ```
     /// fn f(spam: String) {}
     /// fn main {
     ///     let foo = String::new();
@@ -219,6 +350,7 @@ pub fn score(self) -> u32 {
         let mut score = 0;
         let CompletionRelevance {
             exact_name_match,
+            fuzzy_name_match,
             type_match,
             is_local,
             is_item_from_trait,
@@ -228,12 +360,27 @@ pub fn score(self) -> u32 {
             is_private_editable,
             postfix_match,
             is_definite,
+            is_try_convertible,
+            returns_receiver_type,
+            shadows_prelude_item: _,
+            is_implied_bound,
+            popularity_rank,
+            doc_mentions_prefix,
+            is_private_item,
+            is_bound_method,
+            is_private_field_getter,
+            is_redundant_try,
+            is_suggested_by_context,
         } = self;
 
         // lower rank private things
         if !is_private_editable {
             score += 1;
         }
+        // lower rank `?` calls that only rewrap the value they unwrap
+        if !is_redundant_try {
+            score += 1;
+        }
         // lower rank trait op methods
         if !is_op_method {
             score += 10;
@@ -249,6 +396,9 @@ pub fn score(self) -> u32 {
         if exact_name_match {
             score += 10;
         }
+        if fuzzy_name_match {
+            score += 5;
+        }
         score += match postfix_match {
             Some(CompletionRelevancePostfixMatch::Exact) => 100,
             Some(CompletionRelevancePostfixMatch::NonExact) => 0,
@@ -256,6 +406,7 @@ pub fn score(self) -> u32 {
         };
         score += match type_match {
             Some(CompletionRelevanceTypeMatch::Exact) => 8,
+            Some(CompletionRelevanceTypeMatch::Coerced) => 6,
             Some(CompletionRelevanceTypeMatch::CouldUnify) => 3,
             None => 0,
         };
@@ -269,6 +420,42 @@ pub fn score(self) -> u32 {
         if is_definite {
             score += 10;
         }
+        // prefer `?` calls that actually compile
+        if is_try_convertible {
+            score += 1;
+        }
+        // prefer what a generic receiver was declared to be capable of
+        if is_bound_method {
+            score += 1;
+        }
+        // prefer the getter of a field that can't be accessed directly
+        if is_private_field_getter {
+            score += 1;
+        }
+        // slightly prefer methods that can be chained further
+        if returns_receiver_type {
+            score += 2;
+        }
+        // slightly prefer what the surrounding code calls for
+        if is_suggested_by_context {
+            score += 2;
+        }
+        // prefer bounds the function body already relies on
+        if is_implied_bound {
+            score += 5;
+        }
+        // prefer commonly used items
+        if let Some(rank) = popularity_rank {
+            score += 10u32.saturating_sub(rank.into());
+        }
+        // slightly prefer items documented as what's being typed, docs are no stronger signal
+        if doc_mentions_prefix {
+            score += 1;
+        }
+        // lower rank items that aren't visible from the cursor
+        if is_private_item {
+            score = score.saturating_sub(1);
+        }
         score
     }
 
@@ -286,6 +473,8 @@ pub enum CompletionItemKind {
     SymbolKind(SymbolKind),
     Binding,
     BuiltinType,
+    /// A group of derives inserted together, like `Debug, Clone, PartialEq`.
+    DeriveGroup,
     InferredType,
     Keyword,
     Method,
@@ -330,6 +519,7 @@ pub(crate) fn tag(self) -> &'static str {
             },
             CompletionItemKind::Binding => "bn",
             CompletionItemKind::BuiltinType => "bt",
+            CompletionItemKind::DeriveGroup => "dg",
             CompletionItemKind::InferredType => "it",
             CompletionItemKind::Keyword => "kw",
             CompletionItemKind::Method => "me",
@@ -359,6 +549,7 @@ pub(crate) fn new(
             text_edit: None,
             deprecated: false,
             trigger_call_info: false,
+            is_trait_method: false,
             relevance: CompletionRelevance::default(),
             ref_match: None,
             imports_to_add: Default::default(),
@@ -373,10 +564,11 @@ pub fn lookup(&self) -> &str {
 
     pub fn ref_match(&self) -> Option<(String, text_edit::Indel, CompletionRelevance)> {
         // Relevance of the ref match should be the same as the original
-        // match, but with exact type match set because self.ref_match
-        // is only set if there is an exact type match.
+        // match, but with coerced type match set because self.ref_match
+        // is only set if the type matches once referenced. This ranks it below
+        // items matching the expected type as they are.
         let mut relevance = self.relevance;
-        relevance.type_match = Some(CompletionRelevanceTypeMatch::Exact);
+        relevance.type_match = Some(CompletionRelevanceTypeMatch::Coerced);
 
         self.ref_match.map(|(mutability, offset)| {
             (
@@ -406,6 +598,7 @@ pub(crate) struct Builder {
     text_edit: Option<TextEdit>,
     deprecated: bool,
     trigger_call_info: bool,
+    is_trait_method: bool,
     relevance: CompletionRelevance,
     ref_match: Option<(Mutability, TextSize)>,
 }
@@ -498,6 +691,7 @@ pub(crate) fn build(self, db: &RootDatabase) -> CompletionItem {
             kind: self.kind,
             deprecated: self.deprecated,
             trigger_call_info: self.trigger_call_info,
+            is_trait_method: self.is_trait_method,
             relevance: self.relevance,
             ref_match: self.ref_match,
             import_to_add,
@@ -575,6 +769,10 @@ pub(crate) fn with_relevance(
         self.relevance = relevance(mem::take(&mut self.relevance));
         self
     }
+    pub(crate) fn set_is_trait_method(&mut self, is_trait_method: bool) -> &mut Builder {
+        self.is_trait_method = is_trait_method;
+        self
+    }
     pub(crate) fn trigger_call_info(&mut self) -> &mut Builder {
         self.trigger_call_info = true;
         self
@@ -645,10 +843,25 @@ fn relevance_score() {
             vec![Cr { is_op_method: true, is_private_editable: true, ..default }],
             vec![Cr { is_op_method: true, ..default }],
             vec![Cr { postfix_match: Some(CompletionRelevancePostfixMatch::NonExact), ..default }],
-            vec![Cr { is_private_editable: true, ..default }],
+            vec![
+                Cr { is_private_editable: true, ..default },
+                Cr { is_private_item: true, ..default },
+                Cr { is_redundant_try: true, ..default },
+            ],
             vec![default],
-            vec![Cr { is_local: true, ..default }],
+            vec![
+                Cr { is_local: true, ..default },
+                Cr { is_try_convertible: true, ..default },
+                Cr { is_bound_method: true, ..default },
+                Cr { is_private_field_getter: true, ..default },
+                Cr { doc_mentions_prefix: true, ..default },
+            ],
+            vec![
+                Cr { returns_receiver_type: true, ..default },
+                Cr { is_suggested_by_context: true, ..default },
+            ],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::CouldUnify), ..default }],
+            vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::Coerced), ..default }],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::Exact), ..default }],
             vec![Cr { exact_name_match: true, ..default }],
             vec![Cr { exact_name_match: true, is_local: true, ..default }],
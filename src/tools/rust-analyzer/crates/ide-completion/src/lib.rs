@@ -33,7 +33,7 @@
 };
 
 pub use crate::{
-    config::{CallableSnippets, CompletionConfig},
+    config::{CallParenCursor, CallableSnippets, CompletionConfig, CompletionMatchingMode},
     item::{
         CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevancePostfixMatch,
     },
@@ -219,11 +219,20 @@ pub fn completions(
                     attr,
                 );
             }
+            CompletionAnalysis::MacroFragmentSpecifier => {
+                completions::fragment_specifier::complete_fragment_specifier(acc, ctx);
+            }
             CompletionAnalysis::UnexpandedAttrTT { .. } | CompletionAnalysis::String { .. } => (),
         }
     }
 
-    Some(completions.into())
+    let mut completions: Vec<CompletionItem> = completions.into();
+    if config.matching_mode == CompletionMatchingMode::Prefix {
+        // Like fuzzy matching, prefix matching ignores case.
+        let prefix = ctx.typed_prefix().to_lowercase();
+        completions.retain(|item| item.lookup().to_lowercase().starts_with(&prefix));
+    }
+    Some(completions)
 }
 
 /// Resolves additional completion data at the position given.
@@ -268,7 +277,12 @@ pub fn resolve_completion_edits(
             })
             .find(|mod_path| mod_path.display(db).to_string() == full_import_path);
         if let Some(import_path) = import {
-            insert_use::insert_use(&new_ast, mod_path_to_ast(&import_path), &config.insert_use);
+            insert_use::insert_use_with_strategy(
+                &new_ast,
+                mod_path_to_ast(&import_path),
+                &config.insert_use,
+                config.import_insert_strategy,
+            );
         }
     });
 
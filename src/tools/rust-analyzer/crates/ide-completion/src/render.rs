@@ -10,7 +10,7 @@
 pub(crate) mod union_literal;
 pub(crate) mod literal;
 
-use hir::{AsAssocItem, HasAttrs, HirDisplay, ModuleDef, ScopeDef, Type};
+use hir::{AsAssocItem, HasAttrs, HasVisibility, HirDisplay, ModuleDef, ScopeDef, Type};
 use ide_db::{
     documentation::{Documentation, HasDocs},
     helpers::item_name,
@@ -21,7 +21,9 @@
 use text_edit::TextEdit;
 
 use crate::{
-    context::{DotAccess, DotAccessKind, PathCompletionCtx, PathKind, PatternContext},
+    context::{
+        DotAccess, DotAccessKind, PathCompletionCtx, PathKind, PatternContext, TypeLocation,
+    },
     item::{Builder, CompletionRelevanceTypeMatch},
     render::{
         function::render_fn,
@@ -354,20 +356,39 @@ fn render_resolution_path(
     ) && config.callable.is_some();
     if type_path_no_ty_args {
         if let Some(cap) = cap {
-            let has_non_default_type_params = match resolution {
-                ScopeDef::ModuleDef(hir::ModuleDef::Adt(it)) => it.has_non_default_type_params(db),
+            let generic_def = match resolution {
+                ScopeDef::ModuleDef(hir::ModuleDef::Adt(it)) => {
+                    it.has_non_default_type_params(db).then(|| hir::GenericDef::from(it))
+                }
                 ScopeDef::ModuleDef(hir::ModuleDef::TypeAlias(it)) => {
-                    it.has_non_default_type_params(db)
+                    it.has_non_default_type_params(db).then(|| hir::GenericDef::from(it))
                 }
-                _ => false,
+                // Traits are only instantiated like a type when implementing them.
+                ScopeDef::ModuleDef(hir::ModuleDef::Trait(it))
+                    if matches!(
+                        path_ctx.kind,
+                        PathKind::Type { location: TypeLocation::ImplTrait }
+                    ) =>
+                {
+                    let generic_def = hir::GenericDef::from(it);
+                    (generic_arg_slots(db, generic_def) > 0).then_some(generic_def)
+                }
+                _ => None,
             };
 
-            if has_non_default_type_params {
+            if let Some(generic_def) = generic_def {
                 cov_mark::hit!(inserts_angle_brackets_for_generics);
                 item.lookup_by(name.clone())
                     .label(SmolStr::from_iter([&name, "<…>"]))
                     .trigger_call_info()
-                    .insert_snippet(cap, format!("{}<$0>", local_name.display(db)));
+                    .insert_snippet(
+                        cap,
+                        format!(
+                            "{}{}",
+                            local_name.display(db),
+                            generic_args_snippet(db, generic_def)
+                        ),
+                    );
             }
         }
     }
@@ -377,11 +398,16 @@ fn render_resolution_path(
             item.detail(ty.display(db).to_string());
         }
 
-        item.set_relevance(CompletionRelevance {
+        item.with_relevance(|relevance| CompletionRelevance {
             type_match: compute_type_match(completion, &ty),
             exact_name_match: compute_exact_name_match(completion, &name),
+            fuzzy_name_match: matches!(resolution, ScopeDef::Local(_))
+                && compute_fuzzy_name_match(completion, &name),
             is_local: matches!(resolution, ScopeDef::Local(_)),
             requires_import,
+            shadows_prelude_item: compute_shadows_prelude_item(completion, &local_name, resolution),
+            doc_mentions_prefix: relevance.doc_mentions_prefix,
+            is_private_item: relevance.is_private_item,
             ..CompletionRelevance::default()
         });
 
@@ -415,6 +441,29 @@ fn render_resolution_path(
     item
 }
 
+/// Builds the `<..>` scaffold for a generic type, with a tabstop for every lifetime and for
+/// every type or const parameter that has no default.
+fn generic_args_snippet(db: &RootDatabase, def: hir::GenericDef) -> String {
+    let slots = generic_arg_slots(db, def);
+    let mut args: Vec<String> = (1..slots).map(|idx| format!("${idx}")).collect();
+    args.push("$0".to_owned());
+    format!("<{}>", args.join(", "))
+}
+
+/// The number of generic arguments that have to be spelled out when referring to `def`.
+fn generic_arg_slots(db: &RootDatabase, def: hir::GenericDef) -> usize {
+    def.params(db)
+        .into_iter()
+        .filter(|param| match param {
+            hir::GenericParam::LifetimeParam(_) => true,
+            hir::GenericParam::TypeParam(it) => {
+                !it.is_implicit(db) && it.default(db).map_or(true, |ty| ty.is_unknown())
+            }
+            hir::GenericParam::ConstParam(it) => it.default(db).is_none(),
+        })
+        .count()
+}
+
 fn render_resolution_simple_(
     ctx: RenderContext<'_>,
     local_name: &hir::Name,
@@ -427,11 +476,20 @@ fn render_resolution_simple_(
     let ctx = ctx.import_to_add(import_to_add);
     let kind = res_to_kind(resolution);
 
+    let shadows_prelude_item = compute_shadows_prelude_item(ctx.completion, local_name, resolution);
+    let docs = scope_def_docs(db, resolution);
+    let doc_mentions_prefix = compute_doc_mentions_prefix(ctx.completion, docs.as_ref());
+    let is_private_item = compute_is_private_item(ctx.completion, resolution);
     let mut item =
         CompletionItem::new(kind, ctx.source_range(), local_name.unescaped().to_smol_str());
-    item.set_relevance(ctx.completion_relevance())
-        .set_documentation(scope_def_docs(db, resolution))
-        .set_deprecated(scope_def_is_deprecated(&ctx, resolution));
+    item.set_relevance(CompletionRelevance {
+        shadows_prelude_item,
+        doc_mentions_prefix,
+        is_private_item,
+        ..ctx.completion_relevance()
+    })
+    .set_documentation(docs)
+    .set_deprecated(scope_def_is_deprecated(&ctx, resolution));
 
     if let Some(import_to_add) = ctx.import_to_add {
         item.add_import(import_to_add);
@@ -441,7 +499,7 @@ fn render_resolution_simple_(
     item
 }
 
-fn res_to_kind(resolution: ScopeDef) -> CompletionItemKind {
+pub(crate) fn res_to_kind(resolution: ScopeDef) -> CompletionItemKind {
     use hir::ModuleDef::*;
     match resolution {
         ScopeDef::Unknown => CompletionItemKind::UnresolvedReference,
@@ -517,6 +575,25 @@ fn compute_type_match(
     ctx: &CompletionContext<'_>,
     completion_ty: &hir::Type,
 ) -> Option<CompletionRelevanceTypeMatch> {
+    // Arguments of `impl Trait` parameters match by their bounds, as the parameter type gets
+    // inferred from the very argument we are completing.
+    if !ctx.expected_impl_traits.is_empty() {
+        let implements_bounds =
+            ctx.expected_impl_traits.iter().all(|&it| completion_ty.impls_trait(ctx.db, it, &[]));
+        return implements_bounds.then_some(CompletionRelevanceTypeMatch::Exact);
+    }
+
+    if let Some(error_trait) = ctx.expected_error_trait {
+        // The function's own error type matches best, but any other error type might be wanted.
+        let expected_match =
+            ctx.expected_type.as_ref().and_then(|ty| match_types(ctx, ty, completion_ty));
+        return expected_match.or_else(|| {
+            completion_ty
+                .impls_trait(ctx.db, error_trait, &[])
+                .then_some(CompletionRelevanceTypeMatch::CouldUnify)
+        });
+    }
+
     let expected_type = ctx.expected_type.as_ref()?;
 
     // We don't ever consider unit type to be an exact type match, since
@@ -528,10 +605,56 @@ fn compute_type_match(
     match_types(ctx, expected_type, completion_ty)
 }
 
+fn compute_shadows_prelude_item(
+    ctx: &CompletionContext<'_>,
+    name: &hir::Name,
+    resolution: ScopeDef,
+) -> bool {
+    if !matches!(resolution, ScopeDef::ModuleDef(_)) {
+        return false;
+    }
+    ctx.prelude_items.get(name).map_or(false, |defs| defs.iter().any(|&def| def != resolution))
+}
+
 fn compute_exact_name_match(ctx: &CompletionContext<'_>, completion_name: &str) -> bool {
     ctx.expected_name.as_ref().map_or(false, |name| name.text() == completion_name)
 }
 
+/// Whether the summary line of `docs` mentions the identifier typed at the cursor, ignoring case.
+fn compute_doc_mentions_prefix(ctx: &CompletionContext<'_>, docs: Option<&Documentation>) -> bool {
+    let token = &ctx.original_token;
+    if token.kind() != SyntaxKind::IDENT || token.text().len() < 3 {
+        return false;
+    }
+    let Some(summary) = docs.and_then(|docs| docs.as_str().lines().next()) else { return false };
+    summary.to_lowercase().contains(&token.text().to_lowercase())
+}
+
+/// Whether `resolution` is an item that isn't visible from the current module, which is only
+/// completed when private editable items are requested.
+fn compute_is_private_item(ctx: &CompletionContext<'_>, resolution: ScopeDef) -> bool {
+    let ScopeDef::ModuleDef(def) = resolution else { return false };
+    !def.visibility(ctx.db).is_visible_from(ctx.db, ctx.module.into())
+}
+
+/// Whether one of the names is made up of the `_`-separated words of the other one, e.g.
+/// `request_timeout` and `timeout`.
+fn compute_fuzzy_name_match(ctx: &CompletionContext<'_>, completion_name: &str) -> bool {
+    let Some(expected_name) = &ctx.expected_name else { return false };
+    let expected_name = expected_name.text();
+    let (expected_name, completion_name) =
+        (expected_name.as_str().trim_matches('_'), completion_name.trim_matches('_'));
+    if expected_name.is_empty() || completion_name.is_empty() || expected_name == completion_name {
+        return false;
+    }
+    let contains_words = |haystack: &str, needle: &str| {
+        haystack.starts_with(&format!("{needle}_"))
+            || haystack.ends_with(&format!("_{needle}"))
+            || haystack.contains(&format!("_{needle}_"))
+    };
+    contains_words(completion_name, expected_name) || contains_words(expected_name, completion_name)
+}
+
 fn compute_ref_match(
     ctx: &CompletionContext<'_>,
     completion_ty: &hir::Type,
@@ -650,18 +773,29 @@ fn check_relevance_(actual: Vec<CompletionItem>, expect: Expect) {
         fn display_relevance(relevance: CompletionRelevance) -> String {
             let relevance_factors = vec![
                 (relevance.type_match == Some(CompletionRelevanceTypeMatch::Exact), "type"),
+                (
+                    relevance.type_match == Some(CompletionRelevanceTypeMatch::Coerced),
+                    "type_coerced",
+                ),
                 (
                     relevance.type_match == Some(CompletionRelevanceTypeMatch::CouldUnify),
                     "type_could_unify",
                 ),
                 (relevance.exact_name_match, "name"),
+                (relevance.fuzzy_name_match, "fuzzy_name"),
                 (relevance.is_local, "local"),
                 (
                     relevance.postfix_match == Some(CompletionRelevancePostfixMatch::Exact),
                     "snippet",
                 ),
                 (relevance.is_op_method, "op_method"),
+                (relevance.returns_receiver_type, "receiver_type"),
                 (relevance.requires_import, "requires_import"),
+                (relevance.is_implied_bound, "implied_bound"),
+                (relevance.doc_mentions_prefix, "doc_mention"),
+                (relevance.is_private_item, "private"),
+                (relevance.is_bound_method, "bound_method"),
+                (relevance.is_private_field_getter, "getter"),
             ]
             .into_iter()
             .filter_map(|(cond, desc)| if cond { Some(desc) } else { None })
@@ -671,6 +805,284 @@ fn display_relevance(relevance: CompletionRelevance) -> String {
         }
     }
 
+    #[test]
+    fn boost_locals_matching_param_name() {
+        check_relevance_for_kinds(
+            r#"
+fn sleep(timeout: u64) {}
+fn main() {
+    let timeout = 0u64;
+    let request_timeout = 0u64;
+    let retries = 0u64;
+    sleep($0)
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Local)],
+            expect![[r#"
+                lc timeout [type+name+local]
+                lc request_timeout [type+fuzzy_name+local]
+                lc retries [type+local]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_locals_matching_record_field_type() {
+        check_relevance_for_kinds(
+            r#"
+struct Foo { bar: u32 }
+fn main() {
+    let name = "foo";
+    let count = 0u32;
+    Foo { bar: $0 };
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Local)],
+            expect![[r#"
+                lc count [type+local]
+                lc name [local]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_locals_implementing_impl_trait_param() {
+        check_relevance_for_kinds(
+            r#"
+trait Display {}
+struct Shown;
+impl Display for Shown {}
+struct Hidden;
+fn show(it: impl Display) {}
+fn main() {
+    let hidden = Hidden;
+    let shown = Shown;
+    show($0)
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Local)],
+            expect![[r#"
+                lc shown [type+local]
+                lc hidden [local]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_bounds_implied_by_method_calls() {
+        check_relevance_for_kinds(
+            r#"
+trait Debug {
+    fn fmt(&self);
+}
+trait Clone {
+    fn clone(&self) -> Self;
+}
+fn dup<T>(it: &T) -> (T, T)
+where
+    T: $0
+{
+    (it.clone(), it.clone())
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Trait)],
+            expect![[r#"
+                tt Clone [implied_bound]
+                tt Debug []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_fn_bounds_implied_by_calls() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: fn
+trait Debug {
+    fn fmt(&self);
+}
+fn apply<F>(f: F, x: u32)
+where
+    F: $0
+{
+    f(x);
+}
+"#,
+            None,
+        );
+        let relevance =
+            |label: &str| items.iter().find(|it| it.label == label).map(|it| it.relevance).unwrap();
+        for label in ["Fn", "FnMut", "FnOnce", "Fn(…) -> …"] {
+            assert!(relevance(label).is_implied_bound, "{label}");
+            assert!(relevance(label).score() > relevance("Debug").score(), "{label}");
+        }
+        assert!(!relevance("Debug").is_implied_bound);
+    }
+
+    #[test]
+    fn doc_mention_ranks_above_unmentioned_items() {
+        check_relevance_for_kinds(
+            r#"
+/// A slow moving thing.
+struct Alpha;
+/// A quick moving thing.
+struct Beta;
+fn main() {
+    let _: qui$0;
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Struct)],
+            expect![[r#"
+                st Beta [doc_mention]
+                st Alpha []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn private_items_rank_below_public_ones() {
+        let mut config = TEST_CONFIG;
+        config.enable_private_editable = true;
+        let mut actual = get_all_items(
+            config,
+            r#"
+mod m {
+    struct Alpha;
+    pub(crate) struct Beta;
+}
+fn main() {
+    let _: m::$0;
+}
+"#,
+            None,
+        );
+        actual.retain(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Struct));
+        actual.sort_by_key(|it| cmp::Reverse(it.relevance.score()));
+        check_relevance_(
+            actual,
+            expect![[r#"
+                st Beta []
+                st Alpha [private]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_methods_of_receiver_bounds() {
+        check_relevance_for_kinds(
+            r#"
+//- minicore: clone
+trait Ext { fn ext(&self) {} }
+impl<T> Ext for T {}
+fn dup<T: Clone>(it: T) {
+    it.$0
+}
+"#,
+            &[CompletionItemKind::Method],
+            expect![[r#"
+                me clone() (as Clone) [receiver_type+bound_method]
+                me ext() (as Ext) []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_getters_of_inaccessible_fields() {
+        check_relevance_for_kinds(
+            r#"
+mod m {
+    pub struct User { name: u32, pub id: u32 }
+    impl User {
+        pub fn name(&self) -> u32 { self.name }
+        pub fn greet(&self) {}
+    }
+}
+fn main(user: m::User) {
+    user.$0
+}
+"#,
+            &[CompletionItemKind::Method, CompletionItemKind::SymbolKind(SymbolKind::Field)],
+            expect![[r#"
+                me name() [getter]
+                fd id []
+                me greet() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_getters_of_inaccessible_fields_through_deref() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: deref
+mod m {
+    pub struct User { name: u32 }
+    impl User {
+        pub fn name(&self) -> u32 { self.name }
+    }
+}
+struct Rc<T>(T);
+impl<T> core::ops::Deref for Rc<T> {
+    type Target = T;
+    fn deref(&self) -> &T { &self.0 }
+}
+fn main(user: Rc<m::User>) {
+    user.$0
+}
+"#,
+            None,
+        );
+        let getter = items.iter().find(|it| it.label == "name()").unwrap();
+        assert!(getter.relevance.is_private_field_getter);
+    }
+
+    #[test]
+    fn boost_fn_error_type_in_result_error_position() {
+        check_relevance_for_kinds(
+            r#"
+//- minicore: result
+struct MyError;
+struct Other;
+fn f() -> Result<(), MyError> {
+    let r: Result<u32, $0>;
+    Ok(())
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Struct)],
+            expect![[r#"
+                st MyError [type]
+                st Other []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn boost_error_types_in_result_error_position() {
+        check_relevance_for_kinds(
+            r#"
+//- minicore: result, error
+struct MyError;
+struct ParseError;
+impl core::fmt::Debug for ParseError {}
+impl core::fmt::Display for ParseError {}
+impl core::error::Error for ParseError {}
+struct Other;
+fn f() -> Result<(), MyError> {
+    let r: Result<u32, $0>;
+    Ok(())
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Struct)],
+            expect![[r#"
+                st MyError [type]
+                st ParseError [type_could_unify]
+                st Other []
+            "#]],
+        );
+    }
+
     #[test]
     fn set_struct_type_completion_info() {
         check_relevance(
@@ -1165,6 +1577,7 @@ fn main() { let _: m::Spam = S$0 }
                         detail: "m::Spam::Bar(i32)",
                         relevance: CompletionRelevance {
                             exact_name_match: false,
+                            fuzzy_name_match: false,
                             type_match: Some(
                                 Exact,
                             ),
@@ -1176,6 +1589,17 @@ fn main() { let _: m::Spam = S$0 }
                             is_private_editable: false,
                             postfix_match: None,
                             is_definite: false,
+                            is_try_convertible: false,
+                            returns_receiver_type: false,
+                            shadows_prelude_item: false,
+                            is_implied_bound: false,
+                            popularity_rank: None,
+                            doc_mentions_prefix: false,
+                            is_private_item: false,
+                            is_bound_method: false,
+                            is_private_field_getter: false,
+                            is_redundant_try: false,
+                            is_suggested_by_context: false,
                         },
                         trigger_call_info: true,
                     },
@@ -1191,6 +1615,7 @@ fn main() { let _: m::Spam = S$0 }
                         detail: "m::Spam::Foo",
                         relevance: CompletionRelevance {
                             exact_name_match: false,
+                            fuzzy_name_match: false,
                             type_match: Some(
                                 Exact,
                             ),
@@ -1202,6 +1627,17 @@ fn main() { let _: m::Spam = S$0 }
                             is_private_editable: false,
                             postfix_match: None,
                             is_definite: false,
+                            is_try_convertible: false,
+                            returns_receiver_type: false,
+                            shadows_prelude_item: false,
+                            is_implied_bound: false,
+                            popularity_rank: None,
+                            doc_mentions_prefix: false,
+                            is_private_item: false,
+                            is_bound_method: false,
+                            is_private_field_getter: false,
+                            is_redundant_try: false,
+                            is_suggested_by_context: false,
                         },
                         trigger_call_info: true,
                     },
@@ -1269,6 +1705,7 @@ fn foo() { A { the$0 } }
                         deprecated: true,
                         relevance: CompletionRelevance {
                             exact_name_match: false,
+                            fuzzy_name_match: false,
                             type_match: Some(
                                 CouldUnify,
                             ),
@@ -1280,6 +1717,17 @@ fn foo() { A { the$0 } }
                             is_private_editable: false,
                             postfix_match: None,
                             is_definite: false,
+                            is_try_convertible: false,
+                            returns_receiver_type: false,
+                            shadows_prelude_item: false,
+                            is_implied_bound: false,
+                            popularity_rank: None,
+                            doc_mentions_prefix: false,
+                            is_private_item: false,
+                            is_bound_method: false,
+                            is_private_field_getter: false,
+                            is_redundant_try: false,
+                            is_suggested_by_context: false,
                         },
                     },
                 ]
@@ -1557,6 +2005,34 @@ fn foo(xs: Vec<i128>)
         );
     }
 
+    #[test]
+    fn inserts_angle_brackets_for_generic_traits_in_impls() {
+        check_edit(
+            "From",
+            r#"
+//- minicore: from
+struct S;
+impl Fro$0 for S {}
+"#,
+            r#"
+struct S;
+impl From<$0> for S {}
+"#,
+        );
+        check_edit(
+            "Clone",
+            r#"
+//- minicore: clone
+struct S;
+impl Clo$0 for S {}
+"#,
+            r#"
+struct S;
+impl Clone for S {}
+"#,
+        );
+    }
+
     #[test]
     fn active_param_relevance() {
         check_relevance(
@@ -1629,14 +2105,40 @@ fn go(world: &WorldSnapshot) { go(w$0) }
             expect![[r#"
                 lc world [type+name+local]
                 st WorldSnapshot {…} []
-                st &WorldSnapshot {…} [type]
+                st &WorldSnapshot {…} [type_coerced]
                 st WorldSnapshot []
-                st &WorldSnapshot [type]
+                st &WorldSnapshot [type_coerced]
                 fn go(…) []
             "#]],
         );
     }
 
+    #[test]
+    fn exact_type_local_outranks_ref_coercion() {
+        check_relevance(
+            r#"
+struct S;
+fn foo(s: &S) {}
+fn main() {
+    let owned = S;
+    let borrowed = &owned;
+    foo($0);
+}
+            "#,
+            expect![[r#"
+                lc borrowed [type+local]
+                lc owned [local]
+                lc &owned [type_coerced+local]
+                st S []
+                st &S [type_coerced]
+                st S []
+                st &S [type_coerced]
+                fn foo(…) []
+                fn main() []
+            "#]],
+        );
+    }
+
     #[test]
     fn too_many_arguments() {
         cov_mark::check!(too_many_arguments);
@@ -1694,6 +2196,29 @@ fn f() {
         );
     }
 
+    #[test]
+    fn score_as_slice_where_slice_expected() {
+        check_relevance_for_kinds(
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn as_slice(&self) -> &[T] { loop {} }
+    fn len(&self) -> usize { 0 }
+}
+fn sum(values: &[u32]) {}
+fn f() {
+    let v = Vec(0u32);
+    sum(v.$0)
+}
+"#,
+            &[CompletionItemKind::Method],
+            expect![[r#"
+                me as_slice() [type]
+                me len() []
+            "#]],
+        );
+    }
+
     #[test]
     fn score_method_name_match_only() {
         check_relevance(
@@ -1727,11 +2252,11 @@ fn main() {
             "#,
             expect![[r#"
                 lc s [name+local]
-                lc &mut s [type+name+local]
+                lc &mut s [type_coerced+name+local]
                 st S []
-                st &mut S [type]
+                st &mut S [type_coerced]
                 st S []
-                st &mut S [type]
+                st &mut S [type_coerced]
                 fn foo(…) []
                 fn main() []
             "#]],
@@ -1800,13 +2325,13 @@ fn main() {
             expect![[r#"
                 lc m [local]
                 lc t [local]
-                lc &t [type+local]
+                lc &t [type_coerced+local]
                 st S []
-                st &S [type]
+                st &S [type_coerced]
                 st S []
-                st &S [type]
+                st &S [type_coerced]
                 st T []
-                st &T [type]
+                st &T [type_coerced]
                 fn foo(…) []
                 fn main() []
                 md core []
@@ -1848,13 +2373,13 @@ fn main() {
             expect![[r#"
                 lc m [local]
                 lc t [local]
-                lc &mut t [type+local]
+                lc &mut t [type_coerced+local]
                 st S []
-                st &mut S [type]
+                st &mut S [type_coerced]
                 st S []
-                st &mut S [type]
+                st &mut S [type_coerced]
                 st T []
-                st &mut T [type]
+                st &mut T [type_coerced]
                 fn foo(…) []
                 fn main() []
                 md core []
@@ -1912,11 +2437,11 @@ fn bar(t: &Foo) {}
 "#,
             expect![[r#"
                 ev Foo::A []
-                ev &Foo::A [type]
+                ev &Foo::A [type_coerced]
                 ev Foo::B []
-                ev &Foo::B [type]
+                ev &Foo::B [type_coerced]
                 en Foo []
-                en &Foo [type]
+                en &Foo [type_coerced]
                 fn bar(…) []
                 fn foo() []
             "#]],
@@ -1948,13 +2473,13 @@ fn main() {
 "#,
             expect![[r#"
                 st S []
-                st &S [type]
+                st &S [type_coerced]
                 st S []
-                st &S [type]
+                st &S [type_coerced]
                 st T []
-                st &T [type]
+                st &T [type_coerced]
                 fn bar() []
-                fn &bar() [type]
+                fn &bar() [type_coerced]
                 fn foo(…) []
                 fn main() []
                 md core []
@@ -1962,6 +2487,47 @@ fn main() []
         )
     }
 
+    #[test]
+    fn chainable_method_relevance() {
+        check_relevance_for_kinds(
+            r#"
+struct Builder;
+struct Built;
+impl Builder {
+    fn build(self) -> Built { Built }
+    fn name(self, name: &str) -> Self { self }
+}
+fn main() {
+    Builder.$0;
+}
+"#,
+            &[CompletionItemKind::Method],
+            expect![[r#"
+                me name(…) [receiver_type]
+                me build() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn flags_items_shadowing_the_prelude() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: option
+struct Option;
+struct Other;
+fn main() {
+    O$0
+}
+"#,
+            None,
+        );
+        let relevance = |label| items.iter().find(|it| it.label == label).unwrap().relevance;
+        assert!(relevance("Option").shadows_prelude_item);
+        assert!(!relevance("Other").shadows_prelude_item);
+    }
+
     #[test]
     fn op_function_relevances() {
         check_relevance(
@@ -1974,7 +2540,7 @@ impl Sub for u32 {}
 fn foo(a: u32) { a.$0 }
 "#,
             expect![[r#"
-                me sub(…) (as Sub) [op_method]
+                me sub(…) (as Sub) [op_method+receiver_type]
             "#]],
         );
         check_relevance(
@@ -2084,6 +2650,7 @@ fn foo() {
                         detail: "fn()",
                         relevance: CompletionRelevance {
                             exact_name_match: false,
+                            fuzzy_name_match: false,
                             type_match: Some(
                                 Exact,
                             ),
@@ -2095,6 +2662,17 @@ fn foo() {
                             is_private_editable: false,
                             postfix_match: None,
                             is_definite: false,
+                            is_try_convertible: false,
+                            returns_receiver_type: false,
+                            shadows_prelude_item: false,
+                            is_implied_bound: false,
+                            popularity_rank: None,
+                            doc_mentions_prefix: false,
+                            is_private_item: false,
+                            is_bound_method: false,
+                            is_private_field_getter: false,
+                            is_redundant_try: false,
+                            is_suggested_by_context: false,
                         },
                     },
                 ]
@@ -7,13 +7,34 @@
 
 pub(crate) fn render_const(ctx: RenderContext<'_>, const_: hir::Const) -> Option<CompletionItem> {
     let _p = profile::span("render_const");
-    render(ctx, const_)
+    render(ctx, const_, None)
 }
 
-fn render(ctx: RenderContext<'_>, const_: hir::Const) -> Option<CompletionItem> {
+/// Renders an associated const together with the path of its type, like `Color::RED`.
+pub(crate) fn render_qualified_const(
+    ctx: RenderContext<'_>,
+    const_: hir::Const,
+    qualifier: &hir::ModPath,
+) -> Option<CompletionItem> {
+    let _p = profile::span("render_qualified_const");
+    render(ctx, const_, Some(qualifier))
+}
+
+fn render(
+    ctx: RenderContext<'_>,
+    const_: hir::Const,
+    qualifier: Option<&hir::ModPath>,
+) -> Option<CompletionItem> {
     let db = ctx.db();
     let name = const_.name(db)?;
-    let (name, escaped_name) = (name.unescaped().to_smol_str(), name.to_smol_str());
+    let (name, escaped_name) = match qualifier {
+        Some(qualifier) => (
+            format!("{}::{}", qualifier.unescaped().display(db), name.unescaped().display(db))
+                .into(),
+            format!("{}::{}", qualifier.display(db), name.display(db)).into(),
+        ),
+        None => (name.unescaped().to_smol_str(), name.to_smol_str()),
+    };
     let detail = const_.display(db).to_string();
 
     let mut item = CompletionItem::new(SymbolKind::Const, ctx.source_range(), name);
@@ -1,16 +1,20 @@
 //! Renderer for function calls.
 
-use hir::{db::HirDatabase, AsAssocItem, HirDisplay};
-use ide_db::{SnippetCap, SymbolKind};
+use hir::{db::HirDatabase, AsAssocItem, HasVisibility, HirDisplay, ScopeDef};
+use ide_db::{ty_filter::TryEnum, SnippetCap, SymbolKind};
 use itertools::Itertools;
 use stdx::{format_to, to_lower_snake_case};
-use syntax::{AstNode, SmolStr};
+use syntax::{ast, AstNode, SmolStr, TextRange};
+use text_edit::TextEdit;
 
 use crate::{
     context::{CompletionContext, DotAccess, DotAccessKind, PathCompletionCtx, PathKind},
     item::{Builder, CompletionItem, CompletionItemKind, CompletionRelevance},
-    render::{compute_exact_name_match, compute_ref_match, compute_type_match, RenderContext},
-    CallableSnippets,
+    render::{
+        compute_doc_mentions_prefix, compute_exact_name_match, compute_is_private_item,
+        compute_ref_match, compute_type_match, RenderContext,
+    },
+    CallParenCursor, CallableSnippets,
 };
 
 #[derive(Debug)]
@@ -26,7 +30,51 @@ pub(crate) fn render_fn(
     func: hir::Function,
 ) -> Builder {
     let _p = profile::span("render_fn");
-    render(ctx, local_name, func, FuncKind::Function(path_ctx))
+    render(ctx, local_name, func, FuncKind::Function(path_ctx), None)
+}
+
+/// Renders a call of a fallible function that is immediately propagated with `?`, i.e.
+/// `foo(…)?`, if the function returns the same `Try` type as the enclosing function.
+///
+/// With `map_err`, the call is only rendered if its error doesn't convert into the one of the
+/// enclosing function, mapping the error explicitly instead, i.e. `foo(…).map_err(|e| …)?`.
+pub(crate) fn render_fallible_fn(
+    ctx: RenderContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    local_name: Option<hir::Name>,
+    func: hir::Function,
+    innermost_ret_ty: &hir::Type,
+    map_err: bool,
+) -> Option<Builder> {
+    let _p = profile::span("render_fallible_fn");
+    if path_ctx.has_call_parens || ctx.snippet_cap().is_none() {
+        return None;
+    }
+    let func_kind = FuncKind::Function(path_ctx);
+    params(ctx.completion, func, &func_kind, false)?;
+    let mut try_call = TryCall::new(ctx.completion, &func.ret_type(ctx.db()), innermost_ret_ty)?;
+    if map_err {
+        try_call.missing_conversion.take()?;
+        try_call.map_err = true;
+    }
+    let mut item = render(ctx, local_name, func, func_kind, Some(try_call));
+    if is_fn_tail_expr(path_ctx) {
+        // `foo()?` in tail position would need to be wrapped in `Ok(…)` again, returning the
+        // call directly is what's usually wanted there
+        item.with_relevance(|r| CompletionRelevance { is_redundant_try: true, ..r });
+    }
+    Some(item)
+}
+
+fn is_fn_tail_expr(path_ctx: &PathCompletionCtx) -> bool {
+    (|| {
+        let expr = ast::PathExpr::cast(path_ctx.path.syntax().parent()?)?;
+        let stmt_list = ast::StmtList::cast(expr.syntax().parent()?)?;
+        let body = ast::BlockExpr::cast(stmt_list.syntax().parent()?)?;
+        let is_tail = stmt_list.tail_expr()?.syntax() == expr.syntax();
+        Some(is_tail && ast::Fn::can_cast(body.syntax().parent()?.kind()))
+    })()
+    .unwrap_or(false)
 }
 
 pub(crate) fn render_method(
@@ -37,7 +85,266 @@ pub(crate) fn render_method(
     func: hir::Function,
 ) -> Builder {
     let _p = profile::span("render_method");
-    render(ctx, local_name, func, FuncKind::Method(dot_access, receiver))
+    let db = ctx.db();
+    let is_trait_method = func.as_assoc_item(db).and_then(|it| it.containing_trait(db)).is_some();
+    let mut item = render(ctx, local_name, func, FuncKind::Method(dot_access, receiver), None);
+    item.set_is_trait_method(is_trait_method);
+    item
+}
+
+/// Renders a method of a trait object that shares its name with a method of another of the
+/// object's traits as a call through its trait, i.e. `Trait::foo(receiver)`, with the trait
+/// path as it has to be written in the current module.
+pub(crate) fn render_method_through_trait(
+    ctx: RenderContext<'_>,
+    dot_access: &DotAccess,
+    func: hir::Function,
+) -> Option<Builder> {
+    let _p = profile::span("render_method_through_trait");
+    let completion = ctx.completion;
+    let db = ctx.db();
+    let DotAccess {
+        receiver: Some(receiver),
+        receiver_ty: Some(receiver_ty),
+        kind: DotAccessKind::Method { has_parens: false },
+    } = dot_access
+    else {
+        return None;
+    };
+    let trait_ = func.as_assoc_item(db)?.containing_trait(db)?;
+    let receiver_range = completion.sema.original_range_opt(receiver.syntax())?.range;
+    if completion.source_range().end() < receiver_range.start() {
+        return None;
+    }
+
+    let self_arg = match func.self_param(db)?.access(db) {
+        _ if receiver_ty.original.is_reference() => receiver.to_string(),
+        hir::Access::Shared => format!("&{receiver}"),
+        hir::Access::Exclusive => format!("&mut {receiver}"),
+        hir::Access::Owned => receiver.to_string(),
+    };
+    // The trait is usually not in scope, as the method is reachable through the trait object.
+    let trait_path = completion.module.find_use_path(
+        db,
+        hir::ModuleDef::Trait(trait_),
+        completion.config.prefer_no_std,
+        completion.config.prefer_prelude,
+    )?;
+    let call = format!("{}::{}({self_arg}", trait_path.display(db), func.name(db).display(db));
+    let has_args = !func.params_without_self(db).is_empty();
+    let range = TextRange::new(receiver_range.start(), completion.source_range().end());
+
+    let mut item = render(ctx, None, func, FuncKind::Method(dot_access, None), None);
+    item.set_is_trait_method(true);
+    match completion.config.snippet_cap {
+        Some(cap) => {
+            let snippet = if has_args { format!("{call}, $0)") } else { format!("{call})$0") };
+            item.snippet_edit(cap, TextEdit::replace(range, snippet));
+        }
+        None => {
+            item.text_edit(TextEdit::replace(range, format!("{call})")));
+        }
+    }
+    Some(item)
+}
+
+/// Whether `func` is a method of one of the traits the type parameter `receiver_ty` is bounded
+/// by, or of one of their supertraits.
+fn is_bound_method(
+    ctx: &CompletionContext<'_>,
+    func: hir::Function,
+    receiver_ty: &hir::Type,
+) -> bool {
+    let db = ctx.db;
+    let Some(param) = receiver_ty.strip_references().as_type_param(db) else { return false };
+    param
+        .trait_bounds(db)
+        .into_iter()
+        .any(|trait_| trait_.items_with_supertraits(db).contains(&hir::AssocItem::Function(func)))
+}
+
+/// Whether `func` is a getter named like a field that isn't visible at the cursor, of one of the
+/// structs `receiver_ty` derefs to.
+fn is_private_field_getter(
+    ctx: &CompletionContext<'_>,
+    func: hir::Function,
+    receiver_ty: &hir::Type,
+) -> bool {
+    let db = ctx.db;
+    if !func.params_without_self(db).is_empty() || func.ret_type(db).is_unit() {
+        return false;
+    }
+    let name = func.name(db);
+    receiver_ty.autoderef(db).any(|ty| match ty.as_adt() {
+        Some(hir::Adt::Struct(strukt)) => strukt
+            .fields(db)
+            .into_iter()
+            .any(|field| field.name(db) == name && !field.is_visible_from(db, ctx.module)),
+        _ => false,
+    })
+}
+
+/// Standard collections that can preallocate their storage with `with_capacity`.
+const CAPACITY_COLLECTIONS: &[&str] =
+    &["BinaryHeap", "HashMap", "HashSet", "OsString", "PathBuf", "String", "Vec", "VecDeque"];
+
+/// Whether `func` is `new` or `with_capacity` of the standard collection that is expected at the
+/// cursor, e.g. `let v: Vec<u8> = Vec::$0`.
+fn is_expected_collection_constructor(ctx: &CompletionContext<'_>, func: hir::Function) -> bool {
+    let db = ctx.db;
+    if func.has_self_param(db) || !matches!(func.name(db).as_str(), Some("new" | "with_capacity")) {
+        return false;
+    }
+    let adt = match func.as_assoc_item(db).map(|it| it.container(db)) {
+        Some(hir::AssocItemContainer::Impl(impl_)) => impl_.self_ty(db).as_adt(),
+        _ => None,
+    };
+    let Some(adt) = adt else { return false };
+    let expected_adt = ctx.expected_type.as_ref().and_then(|ty| ty.as_adt());
+    expected_adt == Some(adt)
+        && CAPACITY_COLLECTIONS.contains(&&*adt.name(db).to_smol_str())
+        && ctx.famous_defs().builtin_crates().any(|krate| krate == adt.module(db).krate())
+}
+
+/// The closure argument scaffold for `unwrap_or_else` called on an `Option` or `Result` in a
+/// function that can't propagate it with `?`, e.g. `|e|` for `res.unwrap_or_else$0` in `fn main()`.
+fn unwrap_or_else_closure(
+    ctx: &CompletionContext<'_>,
+    func: hir::Function,
+    receiver_ty: &hir::Type,
+) -> Option<&'static str> {
+    if func.name(ctx.db).as_str() != Some("unwrap_or_else") {
+        return None;
+    }
+    let closure = match TryEnum::from_ty(&ctx.sema, &receiver_ty.strip_references())? {
+        TryEnum::Option => "||",
+        TryEnum::Result => "|e|",
+    };
+    // `?` in a closure propagates out of the closure, whose return type we don't know here.
+    let ret_ty = ctx
+        .token
+        .parent_ancestors()
+        .find_map(|it| {
+            if ast::ClosureExpr::can_cast(it.kind()) {
+                return Some(None);
+            }
+            let func = ctx.sema.to_def(&ast::Fn::cast(it)?)?;
+            Some(Some(func.ret_type(ctx.db)))
+        })
+        .flatten()?;
+    TryEnum::from_ty(&ctx.sema, &ret_ty).is_none().then_some(closure)
+}
+
+/// Whether `func` is `Iterator::collect`.
+fn is_iterator_collect(ctx: &CompletionContext<'_>, func: hir::Function) -> bool {
+    let trait_ = func.as_assoc_item(ctx.db).and_then(|it| it.containing_trait(ctx.db));
+    trait_.is_some()
+        && trait_ == ctx.famous_defs().core_iter_Iterator()
+        && func.name(ctx.db).as_str() == Some("collect")
+}
+
+/// The turbofish and argument list snippet of a `collect` call, filled with the expected collection
+/// type with its type arguments left to inference, e.g. `::<Vec<_>>()$0` for
+/// `let v: Vec<u8> = iter.collect$0`. Without an expected type the cursor is placed inside of the
+/// turbofish instead.
+pub(crate) fn collect_turbofish(ctx: &CompletionContext<'_>) -> String {
+    let target = ctx.expected_type.as_ref().filter(|ty| !ty.is_unit() && !ty.is_unknown());
+    let target = target.and_then(|ty| match ty.as_adt() {
+        Some(adt) => {
+            let name = adt.name(ctx.db).display(ctx.db).to_string();
+            let n = hir::GenericDef::from(adt)
+                .type_params(ctx.db)
+                .into_iter()
+                .filter(|param| {
+                    param.split(ctx.db).right().map_or(false, |it| it.default(ctx.db).is_none())
+                })
+                .count();
+            match n {
+                0 => Some(name),
+                n => Some(format!("{name}<{}>", vec!["_"; n].join(", "))),
+            }
+        }
+        None => ty.display_source_code(ctx.db, ctx.module.into(), true).ok(),
+    });
+    match target {
+        Some(target) => format!("::<{target}>()$0"),
+        None => "::<$0>()".to_owned(),
+    }
+}
+
+/// The `?` applied to a call rendered by [`render_fallible_fn`].
+#[derive(Debug)]
+struct TryCall {
+    /// The type of the call expression after `?`, e.g. `T` for `Result<T, E>` or `C` for
+    /// `ControlFlow<B, C>`.
+    output_ty: hir::Type,
+    /// The `From` conversion the `?` needs but which does not exist, as `(from, into)`.
+    missing_conversion: Option<(hir::Type, hir::Type)>,
+    /// Whether the `?` converts the error into a different one with an existing `From` impl.
+    converts_error: bool,
+    /// Whether the error is converted by hand with `.map_err(…)` before applying the `?`.
+    map_err: bool,
+}
+
+impl TryCall {
+    fn new(
+        ctx: &CompletionContext<'_>,
+        call_ret_ty: &hir::Type,
+        innermost_ret_ty: &hir::Type,
+    ) -> Option<TryCall> {
+        let control_flow = ctx.famous_defs().core_ops_ControlFlow();
+        let is_control_flow = |ty: &hir::Type| {
+            control_flow.map_or(false, |it| ty.as_adt() == Some(hir::Adt::Enum(it)))
+        };
+        match (is_control_flow(call_ret_ty), is_control_flow(innermost_ret_ty)) {
+            (true, true) => {
+                // `ControlFlow<B, C>` breaks with its `B` as is, there is no conversion involved.
+                let mut call_args = call_ret_ty.type_arguments();
+                let call_break = call_args.next()?;
+                let output_ty = call_args.next()?;
+                let fn_break = innermost_ret_ty.type_arguments().next()?;
+                let is_compatible =
+                    call_break == fn_break || call_break.is_unknown() || fn_break.is_unknown();
+                return is_compatible.then_some(TryCall {
+                    output_ty,
+                    missing_conversion: None,
+                    converts_error: false,
+                    map_err: false,
+                });
+            }
+            (false, false) => (),
+            _ => return None,
+        }
+
+        let mut call_args = call_ret_ty.type_arguments();
+        let output_ty = call_args.next()?;
+        let (missing_conversion, converts_error) = match (
+            TryEnum::from_ty(&ctx.sema, call_ret_ty)?,
+            TryEnum::from_ty(&ctx.sema, innermost_ret_ty)?,
+        ) {
+            (TryEnum::Option, TryEnum::Option) => (None, false),
+            (TryEnum::Result, TryEnum::Result) => {
+                let call_err = call_args.next()?;
+                let fn_err = innermost_ret_ty.type_arguments().nth(1)?;
+                if call_err == fn_err || call_err.is_unknown() || fn_err.is_unknown() {
+                    (None, false)
+                } else {
+                    let has_from = ctx.famous_defs().core_convert_From().map_or(false, |from| {
+                        fn_err.impls_trait(ctx.db, from, &[call_err.clone()])
+                    });
+                    if has_from {
+                        (None, true)
+                    } else {
+                        (Some((call_err, fn_err)), false)
+                    }
+                }
+            }
+            // `Option`s and `Result`s don't mix with `?`, the `Option` has to go through
+            // `ok_or` first.
+            _ => return None,
+        };
+        Some(TryCall { output_ty, missing_conversion, converts_error, map_err: false })
+    }
 }
 
 fn render(
@@ -45,6 +352,7 @@ fn render(
     local_name: Option<hir::Name>,
     func: hir::Function,
     func_kind: FuncKind<'_>,
+    try_call: Option<TryCall>,
 ) -> Builder {
     let db = completion.db;
 
@@ -97,20 +405,57 @@ fn render(
         .filter(|_| !has_call_parens)
         .and_then(|cap| Some((cap, params(ctx.completion, func, &func_kind, has_dot_receiver)?)));
 
+    let call_ty = try_call.as_ref().map_or(&ret_type, |it| &it.output_ty);
+    let returns_receiver_type = match &func_kind {
+        FuncKind::Method(DotAccess { receiver_ty: Some(receiver_ty), .. }, _) => {
+            completion.expected_type.as_ref().map_or(true, |ty| ty.is_unit())
+                && !ret_type.is_unknown()
+                && ret_type.strip_references() == receiver_ty.original.strip_references()
+        }
+        _ => false,
+    };
+    let fallback_closure = match &func_kind {
+        FuncKind::Method(DotAccess { receiver_ty: Some(receiver_ty), .. }, _) => {
+            unwrap_or_else_closure(completion, func, &receiver_ty.original)
+        }
+        _ => None,
+    };
+    let collect_turbofish = match &func_kind {
+        FuncKind::Method(..) if is_iterator_collect(completion, func) => {
+            Some(collect_turbofish(completion))
+        }
+        _ => None,
+    };
     item.set_relevance(CompletionRelevance {
         type_match: if has_call_parens || complete_call_parens.is_some() {
-            compute_type_match(completion, &ret_type)
+            compute_type_match(completion, call_ty)
         } else {
             compute_type_match(completion, &func.ty(db))
         },
         exact_name_match: compute_exact_name_match(completion, &call),
         is_op_method,
+        is_try_convertible: try_call.as_ref().map_or(false, |it| it.converts_error),
+        returns_receiver_type,
+        is_suggested_by_context: is_expected_collection_constructor(completion, func)
+            || fallback_closure.is_some(),
+        is_bound_method: match &func_kind {
+            FuncKind::Method(DotAccess { receiver_ty: Some(receiver_ty), .. }, _) => {
+                is_bound_method(completion, func, &receiver_ty.original)
+            }
+            _ => false,
+        },
+        is_private_field_getter: match &func_kind {
+            FuncKind::Method(DotAccess { receiver_ty: Some(receiver_ty), .. }, _) => {
+                is_private_field_getter(completion, func, &receiver_ty.original)
+            }
+            _ => false,
+        },
         ..ctx.completion_relevance()
     });
 
     match func_kind {
         FuncKind::Function(path_ctx) => {
-            super::path_ref_match(completion, path_ctx, &ret_type, &mut item);
+            super::path_ref_match(completion, path_ctx, call_ty, &mut item);
         }
         FuncKind::Method(DotAccess { receiver: Some(receiver), .. }, _) => {
             if let Some(original_expr) = completion.sema.original_ast_node(receiver.clone()) {
@@ -122,18 +467,48 @@ fn render(
         _ => (),
     }
 
-    let detail = if ctx.completion.config.full_function_signatures {
+    let mut detail = if ctx.completion.config.full_function_signatures {
         detail_full(db, func)
     } else {
         detail(db, func)
     };
-    item.set_documentation(ctx.docs(func))
+    let mut lookup = name.unescaped().to_smol_str();
+    if let Some(try_call) = &try_call {
+        if let Some((from, into)) = &try_call.missing_conversion {
+            format_to!(detail, " (`?` needs `{}: From<{}>`)", into.display(db), from.display(db));
+        }
+        lookup = if try_call.map_err {
+            format!("{lookup}.map_err?").into()
+        } else {
+            format!("{lookup}?").into()
+        };
+    }
+    let docs = ctx.docs(func);
+    item.with_relevance(|r| CompletionRelevance {
+        doc_mentions_prefix: compute_doc_mentions_prefix(completion, docs.as_ref()),
+        is_private_item: compute_is_private_item(completion, ScopeDef::ModuleDef(func.into())),
+        ..r
+    });
+    item.set_documentation(docs)
         .set_deprecated(ctx.is_deprecated(func) || ctx.is_deprecated_assoc_item(func))
         .detail(detail)
-        .lookup_by(name.unescaped().to_smol_str());
+        .lookup_by(lookup);
 
-    if let Some((cap, (self_param, params))) = complete_call_parens {
-        add_call_parens(&mut item, completion, cap, call, escaped_call, self_param, params);
+    if let Some((cap, params)) = complete_call_parens {
+        match (fallback_closure, collect_turbofish) {
+            (Some(closure), _) => {
+                item.label(SmolStr::from_iter([&call, "(…)"]))
+                    .insert_snippet(cap, format!("{escaped_call}({closure} $0)"));
+            }
+            (None, Some(turbofish)) => {
+                item.label(SmolStr::from_iter([&call, "()"]))
+                    .insert_snippet(cap, format!("{escaped_call}{turbofish}"));
+            }
+            (None, None) => {
+                let try_op = try_call.as_ref().map(|it| it.map_err);
+                add_call_parens(&mut item, completion, cap, call, escaped_call, params, try_op);
+            }
+        }
     }
 
     match ctx.import_to_add {
@@ -159,13 +534,20 @@ pub(super) fn add_call_parens<'b>(
     cap: SnippetCap,
     name: SmolStr,
     escaped_name: SmolStr,
-    self_param: Option<hir::SelfParam>,
-    params: Vec<hir::Param>,
+    (self_param, params): (Option<hir::SelfParam>, Vec<hir::Param>),
+    // `Some(map_err)` for a call that is propagated with `?`
+    try_op: Option<bool>,
 ) -> &'b mut Builder {
     cov_mark::hit!(inserts_parens_for_function_calls);
 
-    let (snippet, label_suffix) = if self_param.is_none() && params.is_empty() {
-        (format!("{escaped_name}()$0"), "()")
+    let map_err = try_op == Some(true);
+    let try_op = if try_op == Some(false) { "?" } else { "" };
+    let (mut snippet, label_suffix) = if self_param.is_none() && params.is_empty() {
+        let snippet = match ctx.config.call_paren_cursor {
+            CallParenCursor::Inside => format!("{escaped_name}($0){try_op}"),
+            CallParenCursor::After => format!("{escaped_name}(){try_op}$0"),
+        };
+        (snippet, "()")
     } else {
         builder.trigger_call_info();
         let snippet = if let Some(CallableSnippets::FillArguments) = ctx.config.callable {
@@ -195,7 +577,7 @@ pub(super) fn add_call_parens<'b>(
             match self_param {
                 Some(self_param) => {
                     format!(
-                        "{}(${{1:{}}}{}{})$0",
+                        "{}(${{1:{}}}{}{}){try_op}$0",
                         escaped_name,
                         self_param.display(ctx.db),
                         if params.is_empty() { "" } else { ", " },
@@ -203,17 +585,25 @@ pub(super) fn add_call_parens<'b>(
                     )
                 }
                 None => {
-                    format!("{escaped_name}({function_params_snippet})$0")
+                    format!("{escaped_name}({function_params_snippet}){try_op}$0")
                 }
             }
         } else {
             cov_mark::hit!(suppress_arg_snippets);
-            format!("{escaped_name}($0)")
+            format!("{escaped_name}($0){try_op}")
         };
 
         (snippet, "(…)")
     };
-    builder.label(SmolStr::from_iter([&name, label_suffix])).insert_snippet(cap, snippet)
+    if map_err {
+        // The final cursor moves into the closure mapping the error.
+        snippet = match snippet.strip_suffix("$0") {
+            Some(call) => format!("{call}.map_err(|e| $0)?"),
+            None => format!("{}.map_err(|e| $0)?", snippet.replace("$0", "$1")),
+        };
+    }
+    let try_op = if map_err { ".map_err(…)?" } else { try_op };
+    builder.label(SmolStr::from_iter([&name, label_suffix, try_op])).insert_snippet(cap, snippet)
 }
 
 fn ref_of_param(ctx: &CompletionContext<'_>, arg: &str, ty: &hir::Type) -> &'static str {
@@ -325,10 +715,92 @@ fn params(
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests::{check_edit, check_edit_with_config, TEST_CONFIG},
-        CallableSnippets, CompletionConfig,
+        tests::{check_edit, check_edit_with_config, get_all_items, relevance_scores, TEST_CONFIG},
+        CallParenCursor, CallableSnippets, CompletionConfig,
     };
 
+    #[test]
+    fn classifies_inherent_and_trait_methods() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+struct S;
+impl S {
+    fn inherent(&self) {}
+}
+trait Tr {
+    fn provided(&self) {}
+}
+impl Tr for S {}
+fn f(s: S) {
+    s.$0
+}
+"#,
+            None,
+        );
+        let is_trait_method = |label: &str| {
+            items.iter().find(|it| it.label == label).map(|it| it.is_trait_method).unwrap()
+        };
+        assert!(!is_trait_method("inherent()"));
+        assert!(is_trait_method("provided()"));
+    }
+
+    #[test]
+    fn call_paren_cursor_placement() {
+        let fixture = r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn no_args() {}
+fn main() { no_$0 }
+"#;
+        check_edit_with_config(
+            CompletionConfig { call_paren_cursor: CallParenCursor::After, ..TEST_CONFIG },
+            "no_args",
+            fixture,
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn no_args() {}
+fn main() { no_args()$0 }
+"#,
+        );
+        check_edit_with_config(
+            CompletionConfig { call_paren_cursor: CallParenCursor::Inside, ..TEST_CONFIG },
+            "no_args",
+            fixture,
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn no_args() {}
+fn main() { no_args($0) }
+"#,
+        );
+        check_edit_with_config(
+            CompletionConfig { call_paren_cursor: CallParenCursor::Inside, ..TEST_CONFIG },
+            "len",
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn bar(s: &S) { s.l$0 }
+"#,
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+fn bar(s: &S) { s.len($0) }
+"#,
+        );
+    }
+
     #[test]
     fn inserts_parens_for_function_calls() {
         cov_mark::check!(inserts_parens_for_function_calls);
@@ -603,6 +1075,285 @@ fn main() {
         );
     }
 
+    #[test]
+    fn complete_fallible_call_with_convertible_error() {
+        let fixture = r#"
+//- minicore: result, from
+struct Error;
+struct IoError;
+impl From<IoError> for Error {
+    fn from(_: IoError) -> Error { Error }
+}
+fn read() -> Result<u32, IoError> { Ok(0) }
+fn run() -> Result<(), Error> {
+    let n = rea$0;
+}
+"#;
+        check_edit(
+            "read?",
+            fixture,
+            r#"
+struct Error;
+struct IoError;
+impl From<IoError> for Error {
+    fn from(_: IoError) -> Error { Error }
+}
+fn read() -> Result<u32, IoError> { Ok(0) }
+fn run() -> Result<(), Error> {
+    let n = read()?$0;
+}
+"#,
+        );
+
+        let items = get_all_items(TEST_CONFIG, fixture, None);
+        let item = items.iter().find(|it| it.lookup() == "read?").unwrap();
+        assert!(item.relevance.is_try_convertible);
+        assert_eq!(item.detail.as_deref(), Some("fn() -> Result<u32, IoError>"));
+    }
+
+    #[test]
+    fn fallible_call_with_same_error_is_not_marked_convertible() {
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: result, from
+struct Error;
+fn read() -> Result<u32, Error> { Ok(0) }
+fn run() -> Result<(), Error> {
+    let n = rea$0;
+}
+"#,
+            None,
+        );
+        let item = items.iter().find(|it| it.lookup() == "read?").unwrap();
+        assert!(!item.relevance.is_try_convertible);
+    }
+
+    #[test]
+    fn boosts_map_err_over_fallible_call_with_unconvertible_error() {
+        let fixture = r#"
+//- minicore: result, from
+struct Error;
+struct IoError;
+fn read() -> Result<u32, IoError> { Ok(0) }
+fn run() -> Result<(), Error> {
+    let n = rea$0;
+}
+"#;
+        check_edit(
+            "read.map_err?",
+            fixture,
+            r#"
+struct Error;
+struct IoError;
+fn read() -> Result<u32, IoError> { Ok(0) }
+fn run() -> Result<(), Error> {
+    let n = read().map_err(|e| $0)?;
+}
+"#,
+        );
+
+        let items = get_all_items(TEST_CONFIG, fixture, None);
+        let score = |lookup: &str| {
+            items.iter().find(|it| it.lookup() == lookup).map(|it| it.relevance.score()).unwrap()
+        };
+        assert!(score("read.map_err?") > score("read?"));
+    }
+
+    #[test]
+    fn boosts_capacity_constructors_of_expected_collection() {
+        let score = relevance_scores(
+            r#"
+//- /main.rs crate:main deps:alloc
+use alloc::vec::Vec;
+fn main() {
+    let v: Vec<u8> = Vec::$0;
+}
+//- /alloc.rs crate:alloc
+pub mod vec {
+    pub struct Vec<T>(T);
+    impl<T> Vec<T> {
+        pub fn new() -> Self { loop {} }
+        pub fn with_capacity(capacity: usize) -> Self { loop {} }
+        pub fn from_elem(elem: T) -> Self { loop {} }
+    }
+}
+"#,
+        );
+        assert!(score("with_capacity(…)") > score("from_elem(…)"));
+        assert_eq!(score("with_capacity(…)"), score("new()"));
+    }
+
+    #[test]
+    fn boosts_unwrap_or_else_in_infallible_functions() {
+        let fixture = r#"
+//- /main.rs crate:main deps:core
+use core::result::Result;
+fn main() {
+    let res: Result<u32, ()> = loop {};
+    res.$0
+}
+//- /core.rs crate:core
+pub mod result {
+    pub enum Result<T, E> {
+        Ok(T),
+        Err(E),
+    }
+    impl<T, E> Result<T, E> {
+        pub fn unwrap_or(self, default: T) -> T { loop {} }
+        pub fn unwrap_or_else<F>(self, op: F) -> T { loop {} }
+    }
+}
+"#;
+        let score = relevance_scores(fixture);
+        assert!(score("unwrap_or_else(…)") > score("unwrap_or(…)"));
+        check_edit(
+            "unwrap_or_else",
+            fixture,
+            r#"
+use core::result::Result;
+fn main() {
+    let res: Result<u32, ()> = loop {};
+    res.unwrap_or_else(|e| $0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fills_collect_turbofish_with_expected_type() {
+        let fixture = |expected: &str| {
+            format!(
+                r#"
+//- minicore: iterator, collect
+struct Vec<T>(T);
+struct Map<K, V, S = ()>(K, V, S);
+struct Iter;
+impl Iterator for Iter {{
+    type Item = (u8, u8);
+    fn next(&mut self) -> Option<(u8, u8)> {{ None }}
+}}
+fn main() {{
+    let v{expected} = Iter.$0;
+}}
+"#
+            )
+        };
+        let insert = |expected: &str| {
+            let items = get_all_items(TEST_CONFIG, &fixture(expected), None);
+            let item = items.iter().find(|it| it.lookup() == "collect").unwrap();
+            item.text_edit.iter().map(|indel| indel.insert.clone()).collect::<String>()
+        };
+        assert_eq!(insert(": Vec<(u8, u8)>"), "collect::<Vec<_>>()$0");
+        assert_eq!(insert(": Map<u8, u8>"), "collect::<Map<_, _>>()$0");
+        assert_eq!(insert(""), "collect::<$0>()");
+    }
+
+    #[test]
+    fn fallible_call_in_tail_position_is_demoted() {
+        let try_call_score = |body: &str| {
+            let fixture = format!(
+                r#"
+//- minicore: result
+struct Error;
+fn read() -> Result<u32, Error> {{ Ok(0) }}
+fn run() -> Result<u32, Error> {{
+    {body}
+}}
+"#
+            );
+            let items = get_all_items(TEST_CONFIG, &fixture, None);
+            let item = items.iter().find(|it| it.lookup() == "read?").unwrap();
+            item.relevance.score()
+        };
+        assert!(try_call_score("rea$0") < try_call_score("rea$0;\n    Ok(0)"));
+    }
+
+    #[test]
+    fn complete_fallible_control_flow_call() {
+        check_edit(
+            "step?",
+            r#"
+//- minicore: try
+use core::ops::ControlFlow;
+fn step() -> ControlFlow<u8, u32> { ControlFlow::Continue(0) }
+fn run() -> ControlFlow<u8> {
+    let n = ste$0;
+}
+"#,
+            r#"
+use core::ops::ControlFlow;
+fn step() -> ControlFlow<u8, u32> { ControlFlow::Continue(0) }
+fn run() -> ControlFlow<u8> {
+    let n = step()?$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn complete_fallible_option_call_only_in_option_fns() {
+        check_edit(
+            "find?",
+            r#"
+//- minicore: option
+fn find() -> Option<u32> { None }
+fn run() -> Option<()> {
+    let n = fin$0;
+}
+"#,
+            r#"
+fn find() -> Option<u32> { None }
+fn run() -> Option<()> {
+    let n = find()?$0;
+}
+"#,
+        );
+
+        let items = get_all_items(
+            TEST_CONFIG,
+            r#"
+//- minicore: option, result
+struct Error;
+fn find() -> Option<u32> { None }
+fn run() -> Result<(), Error> {
+    let n = fin$0;
+}
+"#,
+            None,
+        );
+        assert!(items.iter().any(|it| it.lookup() == "find"));
+        assert!(!items.iter().any(|it| it.lookup() == "find?"));
+    }
+
+    #[test]
+    fn complete_fallible_call_in_closure() {
+        check_edit(
+            "read?",
+            r#"
+//- minicore: result, fn
+struct Error;
+fn read() -> Result<u32, Error> { Ok(0) }
+fn main() {
+    let f = || -> Result<u32, Error> {
+        let n = rea$0;
+        Ok(n)
+    };
+}
+"#,
+            r#"
+struct Error;
+fn read() -> Result<u32, Error> { Ok(0) }
+fn main() {
+    let f = || -> Result<u32, Error> {
+        let n = read()?$0;
+        Ok(n)
+    };
+}
+"#,
+        );
+    }
+
     #[test]
     fn complete_fn_param() {
         // has mut kw
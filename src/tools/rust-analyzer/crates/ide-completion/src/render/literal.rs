@@ -5,6 +5,7 @@
     documentation::{Documentation, HasDocs},
     SymbolKind,
 };
+use itertools::Itertools;
 
 use crate::{
     context::{CompletionContext, PathCompletionCtx, PathKind},
@@ -48,6 +49,92 @@ pub(crate) fn render_struct_literal(
     render(ctx, path_ctx, Variant::Struct(strukt), name, path)
 }
 
+/// Renders a literal of a record struct with many fields that lists all of its visible fields,
+/// prefilling the ones with an obvious default value, e.g. `Foo { a: ${1:0}, b: ${2:b}, $0 }`.
+pub(crate) fn render_struct_literal_with_all_fields(
+    ctx: RenderContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    strukt: hir::Struct,
+    path: Option<hir::ModPath>,
+    local_name: Option<hir::Name>,
+) -> Option<Builder> {
+    let _p = profile::span("render_struct_literal_with_all_fields");
+    let completion = ctx.completion;
+    let db = completion.db;
+    let cap = ctx.snippet_cap()?;
+    if strukt.kind(db) != StructKind::Record
+        || matches!(
+            path_ctx,
+            PathCompletionCtx { has_call_parens: true, .. }
+                | PathCompletionCtx { kind: PathKind::Use | PathKind::Type { .. }, .. }
+        )
+    {
+        return None;
+    }
+    let (fields, fields_omitted) = visible_fields(completion, &strukt.fields(db), strukt)?;
+    // A literal can't be written for a struct with fields that aren't visible here.
+    if fields_omitted || fields.len() < 2 {
+        return None;
+    }
+
+    let name = local_name.unwrap_or_else(|| strukt.name(db));
+    let path = path.unwrap_or_else(|| name.into());
+    let escaped_path = path.display(db).to_string();
+    let field_names = fields.iter().map(|field| field.name(db).display(db).to_string());
+    let label = format!("{} {{ {} }}", path.unescaped().display(db), field_names.format(", "));
+    let snippet_fields = fields.iter().enumerate().format_with("", |(idx, field), f| {
+        let name = field.name(db);
+        let placeholder = default_field_value(completion, &field.ty(db))
+            .unwrap_or_else(|| name.unescaped().display(db).to_string());
+        f(&format_args!("{}: ${{{}:{placeholder}}}, ", name.display(db), idx + 1))
+    });
+    let detail = fields.iter().format_with(", ", |field, f| {
+        f(&format_args!("{}: {}", field.name(db).display(db), field.ty(db).display(db)))
+    });
+
+    let mut item = CompletionItem::new(
+        CompletionItemKind::SymbolKind(SymbolKind::Struct),
+        ctx.source_range(),
+        label,
+    );
+    item.detail(format!("{escaped_path} {{ {detail} }}"))
+        .insert_snippet(cap, format!("{escaped_path} {{ {snippet_fields}$0 }}"))
+        .trigger_call_info()
+        .set_documentation(strukt.docs(db))
+        .set_deprecated(ctx.is_deprecated(strukt));
+
+    let ty = strukt.ty(db);
+    item.set_relevance(CompletionRelevance {
+        type_match: compute_type_match(completion, &ty),
+        ..ctx.completion_relevance()
+    });
+    super::path_ref_match(completion, path_ctx, &ty, &mut item);
+
+    if let Some(import_to_add) = ctx.import_to_add {
+        item.add_import(import_to_add);
+    }
+    Some(item)
+}
+
+/// The literal to prefill a field of type `ty` with, if there is an obvious one.
+fn default_field_value(ctx: &CompletionContext<'_>, ty: &hir::Type) -> Option<String> {
+    if ty.is_int_or_uint() {
+        return Some("0".to_owned());
+    }
+    if ty.is_float() {
+        return Some("0.0".to_owned());
+    }
+    let adt = ty.as_adt()?;
+    if !ctx.famous_defs().builtin_crates().any(|krate| krate == adt.module(ctx.db).krate()) {
+        return None;
+    }
+    match adt.name(ctx.db).as_str()? {
+        "String" => Some("String::new()".to_owned()),
+        "Vec" => Some("vec![]".to_owned()),
+        _ => None,
+    }
+}
+
 fn render(
     ctx @ RenderContext { completion, .. }: RenderContext<'_>,
     path_ctx: &PathCompletionCtx,
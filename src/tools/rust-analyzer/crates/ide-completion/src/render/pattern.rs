@@ -1,12 +1,12 @@
 //! Renderer for patterns.
 
-use hir::{db::HirDatabase, Name, StructKind};
+use hir::{db::HirDatabase, HirDisplay, Name, StructKind};
 use ide_db::{documentation::HasDocs, SnippetCap};
 use itertools::Itertools;
 use syntax::SmolStr;
 
 use crate::{
-    context::{ParamContext, ParamKind, PathCompletionCtx, PatternContext},
+    context::{CompletionContext, ParamContext, ParamKind, PathCompletionCtx, PatternContext},
     render::{
         variant::{format_literal_label, format_literal_lookup, visible_fields},
         RenderContext,
@@ -39,7 +39,7 @@ pub(crate) fn render_struct_pat(
 
     let db = ctx.db();
 
-    Some(build_completion(ctx, label, lookup, pat, strukt, strukt.ty(db), false))
+    Some(build_completion(ctx, label, lookup, pat.clone(), pat, strukt, strukt.ty(db), false))
 }
 
 pub(crate) fn render_variant_pat(
@@ -67,23 +67,35 @@ pub(crate) fn render_variant_pat(
         }
     };
 
-    let (label, lookup, pat) = match path_ctx {
+    let (label, lookup, pat, detail) = match path_ctx {
         Some(PathCompletionCtx { has_call_parens: true, .. }) => {
-            (name.clone(), name, escaped_name.to_string())
+            (name.clone(), name, escaped_name.to_string(), escaped_name.to_string())
         }
         _ => {
             let kind = variant.kind(ctx.db());
             let label = format_literal_label(name.as_str(), kind, ctx.snippet_cap());
             let lookup = format_literal_lookup(name.as_str(), kind);
-            let pat = render_pat(
-                &ctx,
-                pattern_ctx,
-                &escaped_name,
-                kind,
-                &visible_fields,
-                fields_omitted,
-            )?;
-            (label, lookup, pat)
+            match try_enum_payload(ctx.completion, pattern_ctx, variant) {
+                Some((binding, payload_ty)) => {
+                    let pat = match ctx.snippet_cap() {
+                        Some(_) => format!("{escaped_name}(${{1:{binding}}})$0"),
+                        None => format!("{escaped_name}({binding})"),
+                    };
+                    let detail = format!("{name}({})", payload_ty.display(ctx.db()));
+                    (label, lookup, pat, detail)
+                }
+                None => {
+                    let pat = render_pat(
+                        &ctx,
+                        pattern_ctx,
+                        &escaped_name,
+                        kind,
+                        &visible_fields,
+                        fields_omitted,
+                    )?;
+                    (label, lookup, pat.clone(), pat)
+                }
+            }
         }
     };
 
@@ -92,17 +104,45 @@ pub(crate) fn render_variant_pat(
         label,
         lookup,
         pat,
+        detail,
         variant,
         enum_ty,
         pattern_ctx.missing_variants.contains(&variant),
     ))
 }
 
+/// The binding and payload type of `Ok`, `Err` or `Some` in an arm of a match on a `Result` or an
+/// `Option`, like `("e", String)` for `Err` in a match on a `Result<u8, String>`.
+fn try_enum_payload(
+    ctx: &CompletionContext<'_>,
+    pattern_ctx: &PatternContext,
+    variant: hir::Variant,
+) -> Option<(&'static str, hir::Type)> {
+    if !pattern_ctx.missing_variants.contains(&variant) {
+        return None;
+    }
+    let enum_ = variant.parent_enum(ctx.db);
+    let scrutinee_ty = ctx.expected_type.as_ref()?.strip_references();
+    if scrutinee_ty.as_adt() != Some(hir::Adt::Enum(enum_)) {
+        return None;
+    }
+    let famous_defs = ctx.famous_defs();
+    let mut payload_tys = scrutinee_ty.type_arguments();
+    let (binding, payload_ty) = match variant.name(ctx.db).as_str()? {
+        "Ok" if famous_defs.core_result_Result() == Some(enum_) => ("t", payload_tys.next()?),
+        "Err" if famous_defs.core_result_Result() == Some(enum_) => ("e", payload_tys.nth(1)?),
+        "Some" if famous_defs.core_option_Option() == Some(enum_) => ("t", payload_tys.next()?),
+        _ => return None,
+    };
+    (!payload_ty.is_unknown()).then_some((binding, payload_ty))
+}
+
 fn build_completion(
     ctx: RenderContext<'_>,
     label: SmolStr,
     lookup: SmolStr,
     pat: String,
+    detail: String,
     def: impl HasDocs + Copy,
     adt_ty: hir::Type,
     // Missing in context of match statement completions
@@ -117,7 +157,7 @@ fn build_completion(
     let mut item = CompletionItem::new(CompletionItemKind::Binding, ctx.source_range(), label);
     item.set_documentation(ctx.docs(def))
         .set_deprecated(ctx.is_deprecated(def))
-        .detail(&pat)
+        .detail(detail)
         .lookup_by(lookup)
         .set_relevance(relevance);
     match ctx.snippet_cap() {
@@ -27,7 +27,7 @@
 use hir::PrefixKind;
 use ide_db::{
     base_db::{fixture::ChangeFixture, FileLoader, FilePosition},
-    imports::insert_use::{ImportGranularity, InsertUseConfig},
+    imports::insert_use::{ImportGranularity, ImportInsertStrategy, InsertUseConfig},
     RootDatabase, SnippetCap,
 };
 use itertools::Itertools;
@@ -35,8 +35,8 @@
 use test_utils::assert_eq_text;
 
 use crate::{
-    resolve_completion_edits, CallableSnippets, CompletionConfig, CompletionItem,
-    CompletionItemKind,
+    resolve_completion_edits, CallParenCursor, CallableSnippets, CompletionConfig, CompletionItem,
+    CompletionItemKind, CompletionMatchingMode,
 };
 
 /// Lots of basic item definitions
@@ -64,8 +64,14 @@ union Union { field: i32 }
     enable_imports_on_the_fly: true,
     enable_self_on_the_fly: true,
     enable_private_editable: false,
+    enable_keyword_completions: true,
+    enable_derive_groups: false,
+    enable_inherent_stubs: false,
     full_function_signatures: false,
+    override_comment: false,
     callable: Some(CallableSnippets::FillArguments),
+    call_paren_cursor: CallParenCursor::After,
+    matching_mode: CompletionMatchingMode::Fuzzy,
     snippet_cap: SnippetCap::new(true),
     prefer_no_std: false,
     prefer_prelude: true,
@@ -76,8 +82,10 @@ union Union { field: i32 }
         group: true,
         skip_glob_imports: true,
     },
+    import_insert_strategy: ImportInsertStrategy::GroupWithSimilar,
     snippets: Vec::new(),
     limit: None,
+    flyimport_max_results: None,
 };
 
 pub(crate) fn completion_list(ra_fixture: &str) -> String {
@@ -256,6 +264,16 @@ pub(crate) fn get_all_items(
     res
 }
 
+/// Returns a lookup of the relevance scores of the completions in `ra_fixture` by their labels,
+/// for tests comparing the ranking of a few items.
+pub(crate) fn relevance_scores(ra_fixture: &str) -> impl Fn(&str) -> u32 {
+    let items = get_all_items(TEST_CONFIG, ra_fixture, None);
+    move |label| match items.iter().find(|it| it.label == label) {
+        Some(it) => it.relevance.score(),
+        None => panic!("can't find {label:?} completion in {items:#?}"),
+    }
+}
+
 #[test]
 fn test_no_completions_in_for_loop_in_kw_pos() {
     assert_eq!(completion_list(r#"fn foo() { for i i$0 }"#), String::new());
@@ -1,7 +1,10 @@
 //! Completion tests for attributes.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, completion_list};
+use crate::{
+    tests::{check_edit, completion_list, get_all_items, relevance_scores, TEST_CONFIG},
+    CompletionConfig, CompletionItemKind,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -19,6 +22,7 @@ fn proc_macros() {
         expect![[r#"
             at allow(…)
             at cfg(…)
+            at cfg_attr(test, derive(…))
             at cfg_attr(…)
             at deny(…)
             at deprecated
@@ -28,6 +32,7 @@ fn proc_macros() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at non_exhaustive
             at repr(…)
@@ -100,6 +105,7 @@ fn attr_on_source_file() {
             at feature(…)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_implicit_prelude
             at no_main
             at no_mangle
@@ -130,6 +136,7 @@ fn attr_on_module() {
             at forbid(…)
             at macro_use
             at must_use
+            at must_use = "…"
             at no_mangle
             at path = "…"
             at warn(…)
@@ -151,6 +158,7 @@ fn attr_on_module() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_implicit_prelude
             at no_mangle
             at warn(…)
@@ -178,6 +186,7 @@ fn attr_on_macro_rules() {
             at macro_export
             at macro_use
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -201,6 +210,7 @@ fn attr_on_macro_def() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -225,6 +235,7 @@ fn attr_on_extern_crate() {
             at forbid(…)
             at macro_use
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -248,6 +259,7 @@ fn attr_on_use() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -271,6 +283,7 @@ fn attr_on_type_alias() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -290,17 +303,19 @@ fn attr_on_struct() {
         expect![[r#"
             at allow(…)
             at cfg(…)
+            at cfg_attr(test, derive(…))
             at cfg_attr(…)
             at deny(…)
             at deprecated
-            at derive           macro derive
+            at derive                 macro derive
             at derive(…)
-            at derive_const     macro derive_const
+            at derive_const           macro derive_const
             at doc = "…"
             at doc(alias = "…")
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at non_exhaustive
             at repr(…)
@@ -319,6 +334,7 @@ fn attr_on_enum() {
         expect![[r#"
             at allow(…)
             at cfg(…)
+            at cfg_attr(test, derive(…))
             at cfg_attr(…)
             at deny(…)
             at deprecated
@@ -328,6 +344,7 @@ fn attr_on_enum() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at non_exhaustive
             at repr(…)
@@ -353,6 +370,7 @@ fn attr_on_const() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -380,6 +398,7 @@ fn attr_on_static() {
             at link_name = "…"
             at link_section = "…"
             at must_use
+            at must_use = "…"
             at no_mangle
             at used
             at warn(…)
@@ -404,7 +423,7 @@ fn attr_on_trait() {
             at doc(hidden)
             at forbid(…)
             at must_use
-            at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -429,6 +448,7 @@ fn attr_on_impl() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -448,6 +468,7 @@ fn attr_on_impl() {
             at doc(hidden)
             at forbid(…)
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -472,6 +493,7 @@ fn attr_on_extern_block() {
             at forbid(…)
             at link
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -492,6 +514,7 @@ fn attr_on_extern_block() {
             at forbid(…)
             at link
             at must_use
+            at must_use = "…"
             at no_mangle
             at warn(…)
             kw crate::
@@ -518,6 +541,40 @@ fn attr_on_variant() {
     );
 }
 
+#[test]
+fn attr_on_match_arm() {
+    check(
+        r#"fn main() { match 0 { #[$0] 0 => (), _ => () } }"#,
+        expect![[r#"
+            at allow(…)
+            at cfg(…)
+            at cfg_attr(…)
+            at deny(…)
+            at forbid(…)
+            at warn(…)
+            kw crate::
+            kw self::
+        "#]],
+    );
+}
+
+#[test]
+fn attr_on_let_stmt() {
+    check(
+        r#"fn main() { #[$0] let x = 0; }"#,
+        expect![[r#"
+            at allow(…)
+            at cfg(…)
+            at cfg_attr(…)
+            at deny(…)
+            at forbid(…)
+            at warn(…)
+            kw crate::
+            kw self::
+        "#]],
+    );
+}
+
 #[test]
 fn attr_on_fn() {
     check(
@@ -539,7 +596,7 @@ fn attr_on_fn() {
             at link_name = "…"
             at link_section = "…"
             at must_use
-            at must_use
+            at must_use = "…"
             at no_mangle
             at panic_handler
             at proc_macro
@@ -556,6 +613,38 @@ fn attr_on_fn() {
     );
 }
 
+#[test]
+fn attr_only_offered_on_applicable_items() {
+    let actual = completion_list(r#"#[$0] struct Foo;"#);
+    assert!(!actual.contains("at inline"), "{actual}");
+    assert!(actual.contains("at repr(…)"), "{actual}");
+
+    let actual = completion_list(r#"#[$0] fn main() {}"#);
+    assert!(!actual.contains("at repr(…)"), "{actual}");
+    assert!(actual.contains("at inline"), "{actual}");
+}
+
+#[test]
+fn must_use_with_message() {
+    check_edit("must_use", r#"#[$0] fn f() -> u32 { 0 }"#, r#"#[must_use] fn f() -> u32 { 0 }"#);
+    check_edit("must_use_msg", r#"#[$0] struct Guard;"#, r#"#[must_use = "$0"] struct Guard;"#);
+}
+
+#[test]
+fn doc_value_and_hidden_forms() {
+    check_edit("doc", r#"#[$0] fn f() {}"#, r#"#[doc = "${0:docs}"] fn f() {}"#);
+    check_edit("dochidden", r#"#[$0] fn f() {}"#, r#"#[doc(hidden)] fn f() {}"#);
+}
+
+#[test]
+fn cfg_attr_test_derive() {
+    check_edit(
+        "cfg_attr_test_derive",
+        r#"#[$0] struct Foo;"#,
+        r#"#[cfg_attr(test, derive(${0:Debug}))] struct Foo;"#,
+    );
+}
+
 #[test]
 fn attr_in_source_file_end() {
     check(
@@ -564,6 +653,7 @@ fn attr_in_source_file_end() {
             at allow(…)
             at automatically_derived
             at cfg(…)
+            at cfg_attr(test, derive(…))
             at cfg_attr(…)
             at cold
             at deny(…)
@@ -583,6 +673,7 @@ fn attr_in_source_file_end() {
             at macro_export
             at macro_use
             at must_use
+            at must_use = "…"
             at no_mangle
             at non_exhaustive
             at panic_handler
@@ -716,6 +807,90 @@ fn empty_derive() {
         );
     }
 
+    #[test]
+    fn derive_popularity_ordering() {
+        let score = relevance_scores(
+            r#"
+//- minicore: derive, copy, clone, ord, eq, default, fmt, hash
+use core::fmt::Debug;
+use core::hash::Hash;
+#[derive($0)] struct Test;
+"#,
+        );
+        assert!(score("Debug") > score("Clone"));
+        assert!(score("Clone") > score("Hash"));
+        assert!(score("Clone") > score("Default"));
+        assert!(score("Debug") > score("PartialEq, Eq, PartialOrd, Ord"));
+    }
+
+    #[test]
+    fn derive_groups_leave_out_present_derives() {
+        let groups = |enable_derive_groups, fixture: &str| -> Vec<String> {
+            let fixture = format!(
+                r#"
+//- minicore: derive, copy, clone, ord, eq, default, fmt, hash
+use core::fmt::Debug;
+use core::hash::Hash;
+{fixture}
+"#
+            );
+            get_all_items(CompletionConfig { enable_derive_groups, ..TEST_CONFIG }, &fixture, None)
+                .into_iter()
+                .filter(|it| it.kind == CompletionItemKind::DeriveGroup)
+                .map(|it| it.label.to_string())
+                .collect()
+        };
+        assert_eq!(
+            groups(true, "#[derive(Debug, $0)] struct Test;"),
+            ["Clone, PartialEq, Eq, Hash", "Clone, Copy, PartialEq, Eq, Hash", "Clone, Default"]
+        );
+        assert_eq!(
+            groups(true, "struct Name;\n#[derive(PartialEq, Eq, $0)] struct Test(Name);"),
+            ["Debug, Clone, Hash", "Debug, Clone, Default"]
+        );
+        // `PartialEq, Eq` is what the completion of `Eq` inserts already.
+        assert!(groups(true, "struct Name;\n#[derive(Debug, Clone, Hash, $0)] struct Test(Name);")
+            .is_empty());
+        assert!(groups(false, "#[derive(Debug, $0)] struct Test;").is_empty());
+    }
+
+    #[test]
+    fn derive_ord_after_existing_eq() {
+        let score = relevance_scores(
+            r#"
+//- minicore: derive, copy, clone, ord, eq, default, fmt, hash
+use core::fmt::Debug;
+use core::hash::Hash;
+#[derive(PartialEq, Eq, $0)] struct Test;
+"#,
+        );
+        assert!(score("PartialOrd, Ord") > score("Clone"));
+        assert!(score("PartialOrd, Ord") > score("Hash"));
+        assert!(score("PartialOrd") > score("Clone"));
+    }
+
+    #[test]
+    fn derive_copy_for_copy_fields() {
+        let score = relevance_scores(
+            r#"
+//- minicore: derive, copy, clone
+#[derive($0)]
+struct Point { x: u32, y: u32 }
+"#,
+        );
+        assert!(score("Clone, Copy") > score("Clone"));
+
+        let score = relevance_scores(
+            r#"
+//- minicore: derive, copy, clone
+struct Name;
+#[derive($0)]
+struct Person { name: Name, age: u32 }
+"#,
+        );
+        assert!(score("Clone, Copy") < score("Clone"));
+    }
+
     #[test]
     fn derive_with_input_before() {
         check_derive(
@@ -883,6 +1058,26 @@ fn lint_qualified() {
         )
     }
 
+    #[test]
+    fn lint_dead_code_boosted_on_unused_private_fn() {
+        let score = relevance_scores(
+            r#"
+#[allow($0)]
+fn unused() {}
+"#,
+        );
+        assert!(score("dead_code") > score("deprecated"));
+
+        let score = relevance_scores(
+            r#"
+#[allow($0)]
+fn used() {}
+fn main() { used() }
+"#,
+        );
+        assert_eq!(score("dead_code"), score("deprecated"));
+    }
+
     #[test]
     fn lint_feature() {
         check_edit(
@@ -892,6 +1087,18 @@ fn lint_feature() {
         )
     }
 
+    #[test]
+    fn lint_feature_inner() {
+        check_edit("let_chains", r#"#![feature($0)]"#, r#"#![feature(let_chains)]"#);
+    }
+
+    #[test]
+    fn lint_feature_already_listed() {
+        let actual = completion_list(r#"#![feature(let_chains, $0)]"#);
+        assert!(!actual.contains("let_chains"), "{actual}");
+        assert!(actual.contains("box_patterns"), "{actual}");
+    }
+
     #[test]
     fn lint_clippy_unqualified() {
         check_edit(
@@ -963,8 +1170,21 @@ fn empty() {
             expect![[r#"
                 ba C
                 ba align($0)
+                ba packed
+                ba transparent
+            "#]],
+        );
+    }
+
+    #[test]
+    fn empty_on_enum() {
+        check_repr(
+            r#"#[repr($0)] enum Test {}"#,
+            expect![[r#"
+                ba C
+                ba align($0)
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -992,19 +1212,7 @@ fn align() {
             r#"#[repr(align(1), $0)] struct Test;"#,
             expect![[r#"
                 ba C
-                ba i16
-                ba i28
-                ba i32
-                ba i64
-                ba i8
-                ba isize
                 ba transparent
-                ba u128
-                ba u16
-                ba u32
-                ba u64
-                ba u8
-                ba usize
             "#]],
         );
     }
@@ -1015,19 +1223,7 @@ fn packed() {
             r#"#[repr(packed, $0)] struct Test;"#,
             expect![[r#"
                 ba C
-                ba i16
-                ba i28
-                ba i32
-                ba i64
-                ba i8
-                ba isize
                 ba transparent
-                ba u128
-                ba u16
-                ba u32
-                ba u64
-                ba u8
-                ba usize
             "#]],
         );
     }
@@ -1035,11 +1231,11 @@ fn packed() {
     #[test]
     fn c() {
         check_repr(
-            r#"#[repr(C, $0)] struct Test;"#,
+            r#"#[repr(C, $0)] enum Test {}"#,
             expect![[r#"
                 ba align($0)
+                ba i128
                 ba i16
-                ba i28
                 ba i32
                 ba i64
                 ba i8
@@ -1058,7 +1254,7 @@ fn c() {
     #[test]
     fn prim() {
         check_repr(
-            r#"#[repr(usize, $0)] struct Test;"#,
+            r#"#[repr(usize, $0)] enum Test {}"#,
             expect![[r#"
                 ba C
                 ba align($0)
@@ -1067,3 +1263,135 @@ fn prim() {
         );
     }
 }
+
+mod derive_helper {
+    use super::*;
+
+    fn check_derive_helper(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn serde_field_keys() {
+        check_derive_helper(
+            r#"
+//- proc_macros: serialize
+#[derive(proc_macros::Serialize)]
+struct Config {
+    #[serde(default, $0)]
+    name: u32,
+}
+"#,
+            expect![[r#"
+                ba alias = "…"
+                ba borrow
+                ba bound = "…"
+                ba deserialize_with = "…"
+                ba flatten
+                ba getter = "…"
+                ba rename = "…"
+                ba serialize_with = "…"
+                ba skip
+                ba skip_deserializing
+                ba skip_serializing
+                ba skip_serializing_if = "…"
+                ba with = "…"
+            "#]],
+        );
+    }
+
+    #[test]
+    fn serde_container_keys() {
+        check_derive_helper(
+            r#"
+//- proc_macros: serialize
+#[derive(proc_macros::Serialize)]
+#[serde($0)]
+struct Config {
+    name: u32,
+}
+"#,
+            expect![[r#"
+                ba bound = "…"
+                ba content = "…"
+                ba crate = "…"
+                ba default
+                ba deny_unknown_fields
+                ba expecting = "…"
+                ba from = "…"
+                ba into = "…"
+                ba remote = "…"
+                ba rename = "…"
+                ba rename_all = "…"
+                ba tag = "…"
+                ba transparent
+                ba try_from = "…"
+                ba untagged
+            "#]],
+        );
+    }
+
+    #[test]
+    fn serde_variant_keys() {
+        check_derive_helper(
+            r#"
+//- proc_macros: serialize
+#[derive(proc_macros::Serialize)]
+enum Shape {
+    #[serde(rename = "circle", $0)]
+    Circle,
+}
+"#,
+            expect![[r#"
+                ba alias = "…"
+                ba borrow
+                ba bound = "…"
+                ba deserialize_with = "…"
+                ba other
+                ba rename_all = "…"
+                ba serialize_with = "…"
+                ba skip
+                ba skip_deserializing
+                ba skip_serializing
+                ba untagged
+                ba with = "…"
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_serde_keys_without_derive() {
+        check_derive_helper(
+            r#"
+struct Config {
+    #[serde($0)]
+    name: u32,
+}
+"#,
+            expect![[r#""#]],
+        );
+    }
+
+    #[test]
+    fn serde_rename() {
+        check_edit(
+            "rename",
+            r#"
+//- proc_macros: serialize
+#[derive(proc_macros::Serialize)]
+struct Config {
+    #[serde($0)]
+    name: u32,
+}
+"#,
+            r#"
+#[derive(proc_macros::Serialize)]
+struct Config {
+    #[serde(rename = "$0")]
+    name: u32,
+}
+"#,
+        );
+    }
+}
@@ -1,7 +1,12 @@
 //! Completion tests for expressions.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::{
+    tests::{
+        check_edit, check_empty, completion_list, get_all_items, BASE_ITEMS_FIXTURE, TEST_CONFIG,
+    },
+    CompletionConfig, CompletionItemKind, CompletionMatchingMode,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}{ra_fixture}"));
@@ -162,6 +167,7 @@ fn local_func() fn()
             kw while
             kw while let
             me self.foo()   fn(self)
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -238,6 +244,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -285,6 +292,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -357,6 +365,7 @@ fn my()        fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -778,6 +787,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -818,6 +828,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -906,6 +917,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -946,6 +958,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -986,6 +999,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1036,6 +1050,7 @@ fn main()      fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1088,6 +1103,7 @@ fn main()                 fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1248,3 +1264,166 @@ fn main() {
         "#]],
     );
 }
+
+#[test]
+fn no_keywords_when_disabled() {
+    let fixture = r#"
+mod module {}
+fn foo() {
+    let x = 92;
+    $0
+}
+"#;
+    let has_keywords = |config| {
+        get_all_items(config, fixture, None).iter().any(|it| it.kind == CompletionItemKind::Keyword)
+    };
+    assert!(has_keywords(TEST_CONFIG));
+    assert!(!has_keywords(CompletionConfig { enable_keyword_completions: false, ..TEST_CONFIG }));
+}
+
+#[test]
+fn prefix_matching_mode_drops_fuzzy_matches() {
+    let fixture = r#"
+struct S;
+impl S {
+    fn get(&self) {}
+    fn forget(&self) {}
+}
+fn foo(s: S) {
+    s.get$0
+}
+"#;
+    let methods = |matching_mode| {
+        let mut methods: Vec<_> =
+            get_all_items(CompletionConfig { matching_mode, ..TEST_CONFIG }, fixture, None)
+                .into_iter()
+                .filter(|it| it.kind == CompletionItemKind::Method)
+                .map(|it| it.lookup().to_owned())
+                .collect();
+        methods.sort();
+        methods
+    };
+    assert_eq!(methods(CompletionMatchingMode::Fuzzy), ["forget", "get"]);
+    assert_eq!(methods(CompletionMatchingMode::Prefix), ["get"]);
+}
+
+#[test]
+fn prefix_matching_mode_ignores_case() {
+    let items = get_all_items(
+        CompletionConfig { matching_mode: CompletionMatchingMode::Prefix, ..TEST_CONFIG },
+        r#"
+struct HashMap;
+fn foo() {
+    let _ = hash$0
+}
+"#,
+        None,
+    );
+    assert!(items.iter().any(|it| it.lookup() == "HashMap"));
+}
+
+#[test]
+fn mem_take_scaffolds_mut_ref_and_imports_mem() {
+    check_edit(
+        "mem::take(&mut …)",
+        r#"
+//- /main.rs crate:main deps:std
+fn main() {
+    let mut count = 0u32;
+    let old = ta$0
+}
+//- /std.rs crate:std
+pub mod mem {
+    pub fn swap<T>(x: &mut T, y: &mut T) {}
+    pub fn replace<T>(dest: &mut T, src: T) -> T { src }
+    pub fn take<T>(dest: &mut T) -> T { loop {} }
+}
+"#,
+        r#"
+use std::mem;
+
+fn main() {
+    let mut count = 0u32;
+    let old = mem::take(&mut $0)
+}
+"#,
+    );
+}
+
+#[test]
+fn mem_replace_boosted_only_with_a_place_of_the_expected_type() {
+    let replace_unifies = |ra_fixture: &str| {
+        get_all_items(TEST_CONFIG, ra_fixture, None)
+            .into_iter()
+            .find(|it| it.label.starts_with("mem::replace"))
+            .unwrap()
+            .relevance
+            .type_match
+            .is_some()
+    };
+    let std = r#"
+//- /std.rs crate:std
+pub mod mem {
+    pub fn replace<T>(dest: &mut T, src: T) -> T { src }
+}
+"#;
+    assert!(replace_unifies(&format!(
+        r#"
+//- /main.rs crate:main deps:std
+struct State {{ count: u32 }}
+fn main(state: &mut State) {{
+    let old: u32 = re$0
+}}
+{std}"#
+    )));
+    assert!(!replace_unifies(&format!(
+        r#"
+//- /main.rs crate:main deps:std
+fn main(name: &mut String) {{
+    let old: u32 = re$0
+}}
+struct String;
+{std}"#
+    )));
+}
+
+#[test]
+fn mem_functions_only_for_their_prefix_or_expected_type() {
+    let mem_labels = |ra_fixture: &str| {
+        get_all_items(TEST_CONFIG, ra_fixture, None)
+            .into_iter()
+            .filter(|it| it.label.starts_with("mem::"))
+            .map(|it| it.label.to_string())
+            .collect::<Vec<_>>()
+    };
+    let std = r#"
+//- /std.rs crate:std
+pub mod mem {
+    pub fn swap<T>(x: &mut T, y: &mut T) {}
+    pub fn replace<T>(dest: &mut T, src: T) -> T { src }
+    pub fn take<T>(dest: &mut T) -> T { loop {} }
+}
+"#;
+    assert_eq!(
+        mem_labels(&format!(
+            r#"
+//- /main.rs crate:main deps:std
+fn main() {{
+    pri$0
+}}
+{std}"#
+        )),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        mem_labels(&format!(
+            r#"
+//- /main.rs crate:main deps:std
+fn main() {{
+    sw$0
+}}
+{std}"#
+        )),
+        ["mem::swap(&mut …, &mut …)"]
+    );
+}
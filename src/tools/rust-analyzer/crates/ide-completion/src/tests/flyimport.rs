@@ -3,10 +3,14 @@
 use crate::{
     context::{CompletionAnalysis, NameContext, NameKind, NameRefKind},
     tests::{check_edit, check_edit_with_config, TEST_CONFIG},
+    CompletionConfig,
 };
 
 fn check(ra_fixture: &str, expect: Expect) {
-    let config = TEST_CONFIG;
+    check_with_config(TEST_CONFIG, ra_fixture, expect);
+}
+
+fn check_with_config(config: CompletionConfig, ra_fixture: &str, expect: Expect) {
     let (db, position) = crate::tests::position(ra_fixture);
     let (ctx, analysis) = crate::context::CompletionContext::new(&db, position, &config).unwrap();
 
@@ -374,6 +378,90 @@ fn main() {
     );
 }
 
+#[test]
+fn trait_method_with_typo() {
+    check(
+        r#"
+//- /lib.rs crate:dep
+pub trait TestTrait {
+    fn random_method(&self);
+}
+pub struct TestStruct;
+impl TestTrait for TestStruct {
+    fn random_method(&self) {}
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    dep::TestStruct.rabdom$0
+}
+"#,
+        expect![[r#"
+            me random_method() (use dep::TestTrait) fn(&self)
+        "#]],
+    );
+}
+
+#[test]
+fn trait_method_with_several_typos() {
+    check(
+        r#"
+//- /lib.rs crate:dep
+pub trait TestTrait {
+    fn random_method(&self);
+}
+pub struct TestStruct;
+impl TestTrait for TestStruct {
+    fn random_method(&self) {}
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    dep::TestStruct.rabdom_methid$0
+}
+"#,
+        expect![[r#"
+            me random_method() (use dep::TestTrait) fn(&self)
+        "#]],
+    );
+}
+
+#[test]
+fn capped_trait_methods_prefer_impls_for_the_receiver() {
+    let fixture = r#"
+//- /lib.rs crate:dep
+pub trait Blanket {
+    fn describe_any(&self) {}
+}
+impl<T> Blanket for T {}
+pub trait Exact {
+    fn describe_struct(&self) {}
+}
+pub struct TestStruct;
+impl Exact for TestStruct {}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    dep::TestStruct.descr$0
+}
+"#;
+
+    check(
+        fixture,
+        expect![[r#"
+            me describe_struct() (use dep::Exact) fn(&self)
+            me describe_any() (use dep::Blanket) fn(&self)
+        "#]],
+    );
+    check_with_config(
+        CompletionConfig { flyimport_max_results: Some(1), ..TEST_CONFIG },
+        fixture,
+        expect![[r#"
+            me describe_struct() (use dep::Exact) fn(&self)
+        "#]],
+    );
+}
+
 #[test]
 fn trait_method_from_alias() {
     let fixture = r#"
@@ -1396,3 +1484,232 @@ pub trait Span2
         "#]],
     );
 }
+
+#[test]
+fn respects_import_insert_strategy() {
+    let fixture = r#"
+//- /lib.rs crate:dep
+pub mod m {
+    pub struct Middle;
+}
+pub mod thing {
+    pub struct Thing;
+}
+
+//- /main.rs crate:main deps:dep
+mod local {
+    pub struct Local;
+}
+
+use core::mem;
+use std::fmt;
+
+use dep::m::Middle;
+
+use crate::local::Local;
+
+fn main() {
+    Thin$0
+}"#;
+    let mut config = TEST_CONFIG;
+    config.insert_use.granularity = ide_db::imports::insert_use::ImportGranularity::Item;
+
+    config.import_insert_strategy = ide_db::imports::insert_use::ImportInsertStrategy::First;
+    check_edit_with_config(
+        config.clone(),
+        "Thing",
+        fixture,
+        r#"
+mod local {
+    pub struct Local;
+}
+
+use dep::thing::Thing;
+use core::mem;
+use std::fmt;
+
+use dep::m::Middle;
+
+use crate::local::Local;
+
+fn main() {
+    Thing
+}"#,
+    );
+
+    config.import_insert_strategy =
+        ide_db::imports::insert_use::ImportInsertStrategy::GroupWithSimilar;
+    check_edit_with_config(
+        config.clone(),
+        "Thing",
+        fixture,
+        r#"
+mod local {
+    pub struct Local;
+}
+
+use core::mem;
+use std::fmt;
+
+use dep::m::Middle;
+use dep::thing::Thing;
+
+use crate::local::Local;
+
+fn main() {
+    Thing
+}"#,
+    );
+
+    // Sorted among all imports, `dep::thing::Thing` comes right before `std::fmt`.
+    config.import_insert_strategy = ide_db::imports::insert_use::ImportInsertStrategy::Alphabetical;
+    check_edit_with_config(
+        config,
+        "Thing",
+        fixture,
+        r#"
+mod local {
+    pub struct Local;
+}
+
+use core::mem;
+use dep::thing::Thing;
+use std::fmt;
+
+use dep::m::Middle;
+
+use crate::local::Local;
+
+fn main() {
+    Thing
+}"#,
+    );
+}
+
+#[test]
+fn flyimports_std_wrapper_types_with_generic_args() {
+    check_edit(
+        "Cow",
+        r#"
+//- /lib.rs crate:std
+pub mod borrow {
+    pub enum Cow<'a, B: ?Sized + 'a> {
+        Borrowed(&'a B),
+    }
+}
+
+//- /main.rs crate:main deps:std
+fn name() -> Co$0 {}
+"#,
+        r#"
+use std::borrow::Cow;
+
+fn name() -> Cow<$1, $0> {}
+"#,
+    );
+    check_edit(
+        "Arc",
+        r#"
+//- /lib.rs crate:std
+pub mod sync {
+    pub struct Arc<T: ?Sized> {
+        ptr: *const T,
+    }
+    pub struct Mutex<T: ?Sized> {
+        data: T,
+    }
+}
+
+//- /main.rs crate:main deps:std
+struct Shared {
+    inner: Ar$0,
+}
+"#,
+        r#"
+use std::sync::Arc;
+
+struct Shared {
+    inner: Arc<$0>,
+}
+"#,
+    );
+    check_edit(
+        "RefCell",
+        r#"
+//- /lib.rs crate:std
+pub mod cell {
+    pub struct RefCell<T: ?Sized> {
+        value: T,
+    }
+}
+pub mod rc {
+    pub struct Rc<T: ?Sized> {
+        ptr: *const T,
+    }
+}
+
+//- /main.rs crate:main deps:std
+struct Node {
+    children: RefC$0,
+}
+"#,
+        r#"
+use std::cell::RefCell;
+
+struct Node {
+    children: RefCell<$0>,
+}
+"#,
+    );
+}
+
+#[test]
+fn imports_and_shortens_long_qualifier() {
+    check_edit(
+        "std::collections::HashMap::",
+        r#"
+//- /lib.rs crate:std
+pub mod collections {
+    pub struct HashMap;
+    impl HashMap {
+        pub fn new() -> Self {
+            HashMap
+        }
+    }
+}
+
+//- /main.rs crate:main deps:std
+fn main() {
+    let map = std::collections::HashMap::$0;
+}
+"#,
+        r#"
+use std::collections::HashMap;
+
+fn main() {
+    let map = HashMap::;
+}
+"#,
+    );
+}
+
+#[test]
+fn does_not_shorten_two_segment_qualifier() {
+    let completions = crate::tests::completion_list(
+        r#"
+//- /lib.rs crate:dep
+pub struct Thing;
+impl Thing {
+    pub fn new() -> Self {
+        Thing
+    }
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    let thing = dep::Thing::$0;
+}
+"#,
+    );
+    assert!(!completions.contains("Thing::"), "{completions}");
+}
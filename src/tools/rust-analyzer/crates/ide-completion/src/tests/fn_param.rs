@@ -172,6 +172,29 @@ fn new($0) {}
     )
 }
 
+#[test]
+fn in_impl_unclosed_param_list() {
+    check(
+        r#"
+struct Foo {}
+
+impl Foo {
+    fn bar($0
+}
+"#,
+        expect![[r#"
+            sp Self
+            st Foo
+            bn &mut self
+            bn &self
+            bn mut self
+            bn self
+            kw mut
+            kw ref
+        "#]],
+    )
+}
+
 #[test]
 fn in_impl_after_self() {
     check(
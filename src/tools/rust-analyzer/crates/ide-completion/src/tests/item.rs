@@ -4,7 +4,7 @@
 //! in [crate::completions::mod_].
 use expect_test::{expect, Expect};
 
-use crate::tests::{completion_list, BASE_ITEMS_FIXTURE};
+use crate::tests::{check_edit, completion_list, BASE_ITEMS_FIXTURE};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}{ra_fixture}"));
@@ -55,6 +55,53 @@ impl Trait for Str$0
     )
 }
 
+#[test]
+fn generic_target_type_in_trait_impl_block() {
+    check_edit(
+        "Wrapper<T>",
+        r#"
+struct Wrapper<T>(T);
+trait Trait {}
+impl<T> Trait for $0
+"#,
+        r#"
+struct Wrapper<T>(T);
+trait Trait {}
+impl<T> Trait for Wrapper<T>
+"#,
+    );
+}
+
+#[test]
+fn ref_target_type_introduces_lifetime_in_impl_header() {
+    check_edit(
+        "&'a Foo",
+        r#"
+struct Foo;
+trait Trait {}
+impl Trait for &Fo$0
+"#,
+        r#"
+struct Foo;
+trait Trait {}
+impl<'a> Trait for &'a Foo
+"#,
+    );
+    check_edit(
+        "&'a Foo",
+        r#"
+struct Foo;
+trait Trait<T> {}
+impl<T> Trait<T> for &Fo$0
+"#,
+        r#"
+struct Foo;
+trait Trait<T> {}
+impl<'a, T> Trait<T> for &'a Foo
+"#,
+    );
+}
+
 #[test]
 fn after_trait_name_in_trait_def() {
     check(
@@ -136,6 +183,22 @@ fn completes_where() {
     );
 }
 
+#[test]
+fn phantom_data_for_unused_type_param() {
+    check_edit(
+        "PhantomData<T>",
+        r#"
+//- minicore: phantom_data
+struct S<T> { $0 }
+"#,
+        r#"
+use core::marker::PhantomData;
+
+struct S<T> { ${1:_marker}: PhantomData<T> }
+"#,
+    );
+}
+
 #[test]
 fn before_record_field() {
     check(
@@ -288,11 +288,12 @@ fn function0() {}
         expect![[r#"
             ct const CONST1: () =
             fn fn function1()
-            ma makro!(…)          macro_rules! makro
+            ma makro!(…)             macro_rules! makro
             md module
             ta type Type1 =
             kw crate::
             kw self::
+            sn implement all missing items
         "#]],
     );
 }
@@ -345,6 +346,7 @@ fn fn function()
             ta type Type =
             kw crate::
             kw self::
+            sn implement all missing items
         "#]],
     );
 }
@@ -381,6 +383,46 @@ fn after_unit_struct() {
     );
 }
 
+#[test]
+fn impl_default_scaffold_for_struct() {
+    check_edit(
+        "impl Default for Foo",
+        r#"
+//- minicore: default
+mod m {
+    struct Foo { a: u32 }
+    $0
+}
+"#,
+        r#"
+mod m {
+    struct Foo { a: u32 }
+    impl Default for Foo {
+    fn default() -> Self {
+        Self { $0 }
+    }
+}
+}
+"#,
+    );
+}
+
+#[test]
+fn impl_default_scaffold_only_for_preceding_struct() {
+    let completions = completion_list(
+        r#"
+//- minicore: default
+struct Foo { a: u32 }
+struct Bar;
+$0
+struct Baz;
+"#,
+    );
+    assert!(completions.contains("impl Default for Bar"), "{completions}");
+    assert!(!completions.contains("impl Default for Foo"), "{completions}");
+    assert!(!completions.contains("impl Default for Baz"), "{completions}");
+}
+
 #[test]
 fn type_in_impl_trait() {
     check_edit(
@@ -468,3 +510,24 @@ impl B for A {
 ",
     )
 }
+
+#[test]
+fn const_fn_next_to_const_fns() {
+    check_edit(
+        "const fn",
+        r#"
+mod consts {
+    const fn kib(n: usize) -> usize { n * 1024 }
+    $0
+}
+"#,
+        r#"
+mod consts {
+    const fn kib(n: usize) -> usize { n * 1024 }
+    const fn $1($2) {
+    $0
+}
+}
+"#,
+    );
+}
@@ -1,7 +1,9 @@
 //! Completion tests for pattern position.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::tests::{
+    check_edit, check_empty, completion_list, get_all_items, BASE_ITEMS_FIXTURE, TEST_CONFIG,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}\n{ra_fixture}"));
@@ -515,6 +517,84 @@ fn foo() {
     );
 }
 
+#[test]
+fn or_pattern_skips_covered_variants() {
+    check_empty(
+        r#"
+enum E {
+    A,
+    B(u32),
+    C,
+}
+fn foo(e: E) {
+    match e {
+        E::A | $0
+    }
+}
+"#,
+        expect![[r#"
+            en E
+            bn E::B(…) E::B($1)$0
+            bn E::C    E::C$0
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
+#[test]
+fn if_let_or_pattern_offers_all_variants() {
+    check_empty(
+        r#"
+enum E {
+    A,
+    B(u32),
+    C,
+}
+fn foo(e: E) {
+    if let E::A | $0 = e {}
+}
+"#,
+        expect![[r#"
+            en E
+            bn E::A    E::A$0
+            bn E::B(…) E::B($1)$0
+            bn E::C    E::C$0
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
+#[test]
+fn nested_or_pattern_offers_all_variants() {
+    check_empty(
+        r#"
+enum F {
+    X,
+    Y,
+}
+enum E {
+    A(F),
+    B,
+}
+fn foo(e: E) {
+    match e {
+        E::A(F::X | $0) => {}
+    }
+}
+"#,
+        expect![[r#"
+            en E
+            en F
+            bn F::X F::X$0
+            bn F::Y F::Y$0
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
 #[test]
 fn completes_enum_variant_pat_escape() {
     cov_mark::check!(enum_variant_pattern_path);
@@ -656,6 +736,78 @@ fn f(v: u32) {
     );
 }
 
+#[test]
+fn completes_const_pattern_of_scrutinee_type() {
+    check_empty(
+        r#"
+const MAX: u8 = 255;
+const NAME: &str = "";
+
+fn f(v: u8) {
+    match v {
+        M$0
+    }
+}
+"#,
+        expect![[r#"
+            ct MAX         const MAX: u8
+            kw mut
+            kw ref
+            sn start..=end
+        "#]],
+    );
+}
+
+#[test]
+fn completes_inherent_consts_of_scrutinee_type() {
+    check_empty(
+        r#"
+#[derive(PartialEq, Eq)]
+struct Color(u8);
+impl Color {
+    const RED: Color = Color(0);
+    const GREEN: Color = Color(1);
+    const MAX: u8 = 255;
+}
+
+fn f(c: Color) {
+    match c {
+        $0
+    }
+}
+"#,
+        expect![[r#"
+            ct Color::GREEN const GREEN: Color
+            ct Color::RED   const RED: Color
+            st Color
+            bn Color(…)     Color($1)$0
+            kw mut
+            kw ref
+        "#]],
+    );
+}
+
+#[test]
+fn completes_range_pattern_for_integer_scrutinee() {
+    check_edit(
+        "start..=end",
+        r#"
+fn f(v: u8) {
+    match v {
+        $0
+    }
+}
+"#,
+        r#"
+fn f(v: u8) {
+    match v {
+        $1..=$2
+    }
+}
+"#,
+    );
+}
+
 #[test]
 fn in_method_param() {
     check_empty(
@@ -819,3 +971,60 @@ pub enum Enum {
         "#]],
     );
 }
+
+#[test]
+fn variant_payload_scaffolds_in_let_conditions() {
+    check_edit(
+        "Some()",
+        r#"
+//- minicore: option
+fn foo(opt: Option<u32>) {
+    if let $0 = opt {}
+}
+"#,
+        r#"
+fn foo(opt: Option<u32>) {
+    if let Some($1)$0 = opt {}
+}
+"#,
+    );
+    check_edit(
+        "Event::Moved{}",
+        r#"
+enum Event { Moved { x: i32, y: i32 }, Closed }
+fn foo(next: fn() -> Event) {
+    while let $0 = next() {}
+}
+"#,
+        r#"
+enum Event { Moved { x: i32, y: i32 }, Closed }
+fn foo(next: fn() -> Event) {
+    while let Event::Moved { x$1, y$2 }$0 = next() {}
+}
+"#,
+    );
+}
+
+#[test]
+fn result_arms_show_payload_types() {
+    let items = get_all_items(
+        TEST_CONFIG,
+        r#"
+//- minicore: result
+struct String;
+fn foo(res: Result<u8, String>) {
+    match res {
+        $0
+    }
+}
+"#,
+        None,
+    );
+    let arm = |lookup: &str| {
+        let item = items.iter().find(|it| it.lookup() == lookup).unwrap();
+        let insert: String = item.text_edit.iter().map(|indel| indel.insert.clone()).collect();
+        (item.detail.clone().unwrap(), insert)
+    };
+    assert_eq!(arm("Ok()"), ("Ok(u8)".to_owned(), "Ok(${1:t})$0".to_owned()));
+    assert_eq!(arm("Err()"), ("Err(String)".to_owned(), "Err(${1:e})$0".to_owned()));
+}
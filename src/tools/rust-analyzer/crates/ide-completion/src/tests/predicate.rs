@@ -43,6 +43,7 @@ struct Foo<'lt, T, const C: usize> where T: $0 {}
             md module
             tt Trait
             kw crate::
+            kw for<'a>
             kw self::
         "#]],
     );
@@ -144,6 +145,7 @@ pub trait Trait {}
         expect![[r#"
             md std
             kw crate::
+            kw for<'a>
             kw self::
         "#]],
     );
@@ -165,6 +167,7 @@ pub trait Trait {}
             md std
             tt Trait
             kw crate::
+            kw for<'a>
             kw self::
         "#]],
     );
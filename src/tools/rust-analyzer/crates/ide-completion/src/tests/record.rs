@@ -1,6 +1,6 @@
 use expect_test::{expect, Expect};
 
-use crate::tests::completion_list;
+use crate::tests::{check_edit, completion_list, get_all_items, TEST_CONFIG};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -182,11 +182,13 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fd ..foo
             fn main()               fn()
             lc foo                  Foo
             lc thing                i32
             md core
             st Foo                  Foo
+            st Foo { foo1, foo2 }   Foo { foo1: u32, foo2: u32 }
             st Foo {…}              Foo { foo1: u32, foo2: u32 }
             tt Default
             bt u32                  u32
@@ -234,6 +236,7 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fd ..foo
             fd foo1                 u32
             fd foo2                 u32
         "#]],
@@ -259,11 +262,37 @@ fn main() {
 "#,
         expect![[r#"
             fd ..Default::default()
+            fd ..foo
             sn ..
         "#]],
     );
 }
 
+#[test]
+fn functional_update_from_local() {
+    check_edit(
+        "..base",
+        r#"
+struct Config { verbose: bool, retries: u32 }
+
+fn main() {
+    let base = Config { verbose: false, retries: 3 };
+    let retries = 5;
+    let config = Config { retries, ..$0 };
+}
+"#,
+        r#"
+struct Config { verbose: bool, retries: u32 }
+
+fn main() {
+    let base = Config { verbose: false, retries: 3 };
+    let retries = 5;
+    let config = Config { retries, ..base };
+}
+"#,
+    );
+}
+
 #[test]
 fn empty_union_literal() {
     check(
@@ -299,3 +328,68 @@ fn foo() {
         expect![[r#""#]],
     )
 }
+
+#[test]
+fn struct_literal_with_all_fields() {
+    check_edit(
+        "Foo { count, name, items, flag }",
+        r#"
+//- /std.rs crate:std
+pub mod string {
+    pub struct String;
+}
+pub mod vec {
+    pub struct Vec<T>(T);
+}
+//- /main.rs crate:main deps:std
+mod m {
+    pub struct Foo {
+        pub count: u32,
+        pub name: std::string::String,
+        pub items: std::vec::Vec<u8>,
+        pub flag: bool,
+    }
+}
+use m::Foo;
+fn main() {
+    let foo = Fo$0;
+}
+"#,
+        r#"
+mod m {
+    pub struct Foo {
+        pub count: u32,
+        pub name: std::string::String,
+        pub items: std::vec::Vec<u8>,
+        pub flag: bool,
+    }
+}
+use m::Foo;
+fn main() {
+    let foo = Foo { count: ${1:0}, name: ${2:String::new()}, items: ${3:vec![]}, flag: ${4:flag}, $0 };
+}
+"#,
+    );
+}
+
+#[test]
+fn no_struct_literal_with_all_fields_for_private_fields() {
+    let items = get_all_items(
+        TEST_CONFIG,
+        r#"
+mod m {
+    pub struct Foo {
+        pub count: u32,
+        pub flag: bool,
+        secret: u8,
+    }
+}
+use m::Foo;
+fn main() {
+    let foo = Fo$0;
+}
+"#,
+        None,
+    );
+    assert!(items.iter().all(|it| !it.label.starts_with("Foo {")));
+}
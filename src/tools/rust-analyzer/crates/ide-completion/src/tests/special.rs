@@ -295,6 +295,46 @@ fn m() (as Trait) fn()
     );
 }
 
+#[test]
+fn completes_default_in_qualified_paths() {
+    check_edit(
+        "default",
+        r#"
+//- minicore: default
+struct Vec<T>(T);
+impl<T: Default> Default for Vec<T> {
+    fn default() -> Self { Vec(T::default()) }
+}
+fn foo() { let _: Vec<u8> = <Vec<u8> as Default>::$0 }
+"#,
+        r#"
+struct Vec<T>(T);
+impl<T: Default> Default for Vec<T> {
+    fn default() -> Self { Vec(T::default()) }
+}
+fn foo() { let _: Vec<u8> = <Vec<u8> as Default>::default()$0 }
+"#,
+    );
+    check_edit(
+        "default",
+        r#"
+//- minicore: default
+struct S;
+impl Default for S {
+    fn default() -> Self { S }
+}
+fn foo() { let _: S = Default::$0 }
+"#,
+        r#"
+struct S;
+impl Default for S {
+    fn default() -> Self { S }
+}
+fn foo() { let _: S = Default::default()$0 }
+"#,
+    );
+}
+
 #[test]
 fn completes_ty_param_assoc_ty() {
     check_no_kw(
@@ -1036,6 +1076,7 @@ fn here_we_go()    fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1085,6 +1126,7 @@ fn here_we_go()           fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1205,6 +1247,7 @@ fn foo() (alias qux) fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
@@ -1460,6 +1503,7 @@ fn foo()       fn()
             kw use
             kw while
             kw while let
+            sn let-else
             sn macro_rules
             sn pd
             sn ppd
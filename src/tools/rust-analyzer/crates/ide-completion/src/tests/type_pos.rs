@@ -1,7 +1,7 @@
 //! Completion tests for type position.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}\n{ra_fixture}"));
@@ -35,6 +35,86 @@ struct Foo<'lt, T, const C: usize> {
     )
 }
 
+#[test]
+fn dyn_trait_lifetime_bound() {
+    check(
+        r#"
+fn foo<'a>(_: Box<dyn Trait $0>) {}
+"#,
+        expect![[r#"
+            lt + 'a
+            lt + 'static
+        "#]],
+    )
+}
+
+#[test]
+fn array_type_len() {
+    let completions = completion_list(
+        r#"
+const LEN: usize = 4;
+mod consts {}
+struct Foo;
+impl Foo {
+    const LEN: usize = 4;
+}
+const fn size_of<T>() -> usize { 0 }
+macro_rules! len { () => { 4 } }
+fn foo<const N: usize>() {
+    let _: [u8; $0];
+}
+"#,
+    );
+    for expected in
+        ["ct LEN", "cp N", "md consts", "st Foo", "fn size_of()", "ma len!(…)", "sn { … }"]
+    {
+        assert!(completions.contains(expected), "{expected} missing in {completions}");
+    }
+}
+
+#[test]
+fn array_type_len_through_type() {
+    check_empty(
+        r#"
+struct Foo;
+impl Foo {
+    const LEN: usize = 4;
+    fn new() -> Foo { Foo }
+}
+fn foo() {
+    let _: [u8; Foo::$0];
+}
+"#,
+        expect![[r#"
+            ct LEN   usize
+            fn new() fn() -> Foo
+        "#]],
+    );
+    let completions = completion_list(
+        r#"
+struct Foo;
+impl Foo {
+    const N: usize = 4;
+    fn buf() -> [u8; Self::$0] { loop {} }
+}
+"#,
+    );
+    assert!(completions.contains("ct N"), "{completions}");
+}
+
+#[test]
+fn where_clause_lifetime_outlives_bound() {
+    check(
+        r#"
+fn foo<'a, 'b, T>() where 'a: $0 {}
+"#,
+        expect![[r#"
+            lt 'b
+            lt 'static
+        "#]],
+    )
+}
+
 #[test]
 fn tuple_struct_field() {
     check(
@@ -246,6 +326,32 @@ fn f2(x: $0) {
     );
 }
 
+#[test]
+fn inferred_type_cast_target() {
+    check(
+        r#"
+fn f1(x: u64) {}
+fn f2(x: u8) {
+    f1(x as $0);
+}
+"#,
+        expect![[r#"
+            en Enum      Enum
+            ma makro!(…) macro_rules! makro
+            md module
+            st Record    Record
+            st Tuple     Tuple
+            st Unit      Unit
+            tt Trait
+            un Union     Union
+            bt u32       u32
+            it _
+            kw crate::
+            kw self::
+        "#]],
+    );
+}
+
 #[test]
 fn inferred_type_not_in_the_scope() {
     check(
@@ -469,6 +575,22 @@ fn func(_: Enum::$0) {}
     );
 }
 
+#[test]
+fn qualified_projection_in_fn_param() {
+    check(
+        r#"
+trait Iterator {
+    type Item;
+    fn next(&mut self);
+}
+fn first<T: Iterator>(it: T, default: <T as Iterator>::$0) {}
+"#,
+        expect![[r#"
+            ta Item (as Iterator) type Item
+        "#]],
+    );
+}
+
 #[test]
 fn completes_type_parameter_or_associated_type() {
     check(
@@ -989,3 +1111,84 @@ fn foo<T: Bar<X$0, ()>>() {}
         "#]],
     );
 }
+
+#[test]
+fn fn_bound_scaffold_for_called_param() {
+    check_edit(
+        "Fn(…) -> …",
+        r#"
+//- minicore: fn
+fn apply<F>(f: F, x: u32)
+where
+    F: $0
+{
+    f(x);
+}
+"#,
+        r#"
+fn apply<F>(f: F, x: u32)
+where
+    F: Fn($1) -> $0
+{
+    f(x);
+}
+"#,
+    );
+}
+
+#[test]
+fn impl_trait_param_offers_traits() {
+    check(
+        r#"
+fn f(x: impl $0) {}
+"#,
+        expect![[r#"
+            ma makro!(…) macro_rules! makro
+            md module
+            tt Trait
+            kw crate::
+            kw self::
+        "#]],
+    );
+    check_edit(
+        "IntoIterator<Item = …>",
+        r#"
+//- minicore: iterator, as_ref
+fn f(x: impl $0) {}
+"#,
+        r#"
+fn f(x: impl IntoIterator<Item = $0>) {}
+"#,
+    );
+    check_edit(
+        "AsRef<str>",
+        r#"
+//- minicore: iterator, as_ref
+fn f(x: impl $0) {}
+"#,
+        r#"
+fn f(x: impl AsRef<str>) {}
+"#,
+    );
+}
+
+#[test]
+fn higher_ranked_bound_scaffold() {
+    check_edit(
+        "for<'a>",
+        r#"
+fn apply<F>(f: F)
+where
+    F: $0
+{
+}
+"#,
+        r#"
+fn apply<F>(f: F)
+where
+    F: for<'${1:a}> $0
+{
+}
+"#,
+    );
+}
@@ -286,6 +286,33 @@ pub mod bar {}
     );
 }
 
+#[test]
+fn pub_use_glob() {
+    check(
+        r#"
+pub mod module {
+    pub struct Foo;
+}
+pub use module::$0;
+"#,
+        expect![[r#"
+            st Foo Foo
+            kw *
+        "#]],
+    );
+    check(
+        r#"
+pub mod module {
+    pub struct Foo;
+}
+use module::$0;
+"#,
+        expect![[r#"
+            st Foo Foo
+        "#]],
+    );
+}
+
 #[test]
 fn pub_suggest_use_tree_super_acc_to_depth_in_tree() {
     // https://github.com/rust-lang/rust-analyzer/issues/12439
@@ -301,6 +328,7 @@ mod bar {
 "#,
         expect![[r#"
             md bar
+            kw *
             kw super::
         "#]],
     );
@@ -316,6 +344,7 @@ mod bar {
 "#,
         expect![[r#"
             md foo
+            kw *
         "#]],
     );
 
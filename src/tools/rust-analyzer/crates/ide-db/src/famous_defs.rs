@@ -1,7 +1,7 @@
 //! See [`FamousDefs`].
 
 use base_db::{CrateOrigin, LangCrateOrigin, SourceDatabase};
-use hir::{Crate, Enum, Macro, Module, ScopeDef, Semantics, Trait};
+use hir::{Crate, Enum, Macro, Module, ScopeDef, Semantics, Struct, Trait};
 
 use crate::RootDatabase;
 
@@ -78,10 +78,30 @@ pub fn core_iter_IntoIterator(&self) -> Option<Trait> {
         self.find_trait("core:iter:traits:collect:IntoIterator")
     }
 
+    pub fn core_iter_DoubleEndedIterator(&self) -> Option<Trait> {
+        self.find_trait("core:iter:traits:double_ended:DoubleEndedIterator")
+    }
+
+    pub fn core_error_Error(&self) -> Option<Trait> {
+        self.find_trait("core:error:Error")
+    }
+
     pub fn core_iter(&self) -> Option<Module> {
         self.find_module("core:iter")
     }
 
+    pub fn core_prelude_v1(&self) -> Option<Module> {
+        self.find_module("core:prelude:v1")
+    }
+
+    pub fn std_prelude_v1(&self) -> Option<Module> {
+        self.find_module("std:prelude:v1")
+    }
+
+    pub fn std_mem(&self) -> Option<Module> {
+        self.find_module("std:mem")
+    }
+
     pub fn core_ops_Deref(&self) -> Option<Trait> {
         self.find_trait("core:ops:Deref")
     }
@@ -98,6 +118,18 @@ pub fn core_ops_ControlFlow(&self) -> Option<Enum> {
         self.find_enum("core:ops:ControlFlow")
     }
 
+    pub fn core_ops_Fn(&self) -> Option<Trait> {
+        self.find_trait("core:ops:Fn")
+    }
+
+    pub fn core_ops_FnMut(&self) -> Option<Trait> {
+        self.find_trait("core:ops:FnMut")
+    }
+
+    pub fn core_ops_FnOnce(&self) -> Option<Trait> {
+        self.find_trait("core:ops:FnOnce")
+    }
+
     pub fn core_ops_Drop(&self) -> Option<Trait> {
         self.find_trait("core:ops:Drop")
     }
@@ -106,6 +138,10 @@ pub fn core_marker_Copy(&self) -> Option<Trait> {
         self.find_trait("core:marker:Copy")
     }
 
+    pub fn core_marker_PhantomData(&self) -> Option<Struct> {
+        self.find_struct("core:marker:PhantomData")
+    }
+
     pub fn core_macros_builtin_derive(&self) -> Option<Macro> {
         self.find_macro("core:macros:builtin:derive")
     }
@@ -135,6 +171,13 @@ fn find_macro(&self, path: &str) -> Option<Macro> {
         }
     }
 
+    fn find_struct(&self, path: &str) -> Option<Struct> {
+        match self.find_def(path)? {
+            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Struct(it))) => Some(it),
+            _ => None,
+        }
+    }
+
     fn find_enum(&self, path: &str) -> Option<Enum> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Enum(it))) => Some(it),
@@ -36,6 +36,18 @@ pub enum ImportGranularity {
     Item,
 }
 
+/// Where a new use statement should be placed if it can't be merged into an existing one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportInsertStrategy {
+    /// Insert the use statement before all existing ones.
+    First,
+    /// Insert the use statement into the group of similar imports, e.g. `std` or crate-local
+    /// ones, if grouping is enabled.
+    GroupWithSimilar,
+    /// Insert the use statement in sorted order, disregarding import groups.
+    Alphabetical,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct InsertUseConfig {
     pub granularity: ImportGranularity,
@@ -157,7 +169,18 @@ pub fn clone_for_update(&self) -> Self {
 
 /// Insert an import path into the given file/node. A `merge` value of none indicates that no import merging is allowed to occur.
 pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
-    insert_use_with_alias_option(scope, path, cfg, None);
+    insert_use_with_alias_option(scope, path, cfg, None, ImportInsertStrategy::GroupWithSimilar);
+}
+
+/// Like [`insert_use`], but places the new use statement according to `strategy` if it can't be
+/// merged into an existing one.
+pub fn insert_use_with_strategy(
+    scope: &ImportScope,
+    path: ast::Path,
+    cfg: &InsertUseConfig,
+    strategy: ImportInsertStrategy,
+) {
+    insert_use_with_alias_option(scope, path, cfg, None, strategy);
 }
 
 pub fn insert_use_as_alias(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
@@ -171,7 +194,7 @@ pub fn insert_use_as_alias(scope: &ImportScope, path: ast::Path, cfg: &InsertUse
         .expect("Failed to make ast node `Rename`");
     let alias = node.rename();
 
-    insert_use_with_alias_option(scope, path, cfg, alias);
+    insert_use_with_alias_option(scope, path, cfg, alias, ImportInsertStrategy::GroupWithSimilar);
 }
 
 fn insert_use_with_alias_option(
@@ -179,6 +202,7 @@ fn insert_use_with_alias_option(
     path: ast::Path,
     cfg: &InsertUseConfig,
     alias: Option<ast::Rename>,
+    strategy: ImportInsertStrategy,
 ) {
     let _p = profile::span("insert_use");
     let mut mb = match cfg.granularity {
@@ -216,7 +240,7 @@ fn insert_use_with_alias_option(
 
     // either we weren't allowed to merge or there is no import that fits the merge conditions
     // so look for the place we have to insert to
-    insert_use_(scope, &path, cfg.group, use_item);
+    insert_use_(scope, &path, cfg.group, strategy, use_item);
 }
 
 pub fn ast_to_remove_for_path_in_use_stmt(path: &ast::Path) -> Option<Box<dyn Removable>> {
@@ -354,6 +378,7 @@ fn insert_use_(
     scope: &ImportScope,
     insert_path: &ast::Path,
     group_imports: bool,
+    strategy: ImportInsertStrategy,
     use_item: ast::Use,
 ) {
     let scope_syntax = scope.as_syntax_node();
@@ -368,7 +393,33 @@ fn insert_use_(
             Some((path, has_tl, node))
         });
 
-    if group_imports {
+    if strategy == ImportInsertStrategy::First {
+        if let Some((.., node)) = path_node_iter.clone().next() {
+            cov_mark::hit!(insert_first);
+            return ted::insert(ted::Position::before(node), use_item.syntax());
+        }
+    } else if strategy == ImportInsertStrategy::Alphabetical {
+        // The existing imports need not be sorted, so rather than stopping at the first import
+        // that sorts after ours, look for the one that comes right after it in sorted order.
+        let cmp = |(a, a_has_tl, _): &(ast::Path, bool, SyntaxNode),
+                   (b, b_has_tl, _): &(ast::Path, bool, SyntaxNode)| {
+            use_tree_path_cmp(a, *a_has_tl, b, *b_has_tl)
+        };
+        let successor = path_node_iter
+            .clone()
+            .filter(|(path, has_tl, _)| {
+                use_tree_path_cmp(insert_path, false, path, *has_tl) != Ordering::Greater
+            })
+            .min_by(cmp);
+        if let Some((.., node)) = successor {
+            cov_mark::hit!(insert_alphabetical);
+            return ted::insert(ted::Position::before(node), use_item.syntax());
+        }
+        if let Some((.., node)) = path_node_iter.clone().max_by(cmp) {
+            cov_mark::hit!(insert_alphabetical_last);
+            return ted::insert(ted::Position::after(node), use_item.syntax());
+        }
+    } else if group_imports {
         // Iterator that discards anything thats not in the required grouping
         // This implementation allows the user to rearrange their import groups as this only takes the first group that fits
         let group_iter = path_node_iter
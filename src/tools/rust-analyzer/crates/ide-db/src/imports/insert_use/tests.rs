@@ -74,6 +74,106 @@ fn insert_skips_lone_glob_imports() {
     );
 }
 
+#[test]
+fn insert_first_strategy() {
+    cov_mark::check!(insert_first);
+    check_with_strategy(
+        "std::fmt::Debug",
+        r"
+use std::fmt::Display;
+
+use crate::foo::Bar;",
+        r"
+use std::fmt::Debug;
+use std::fmt::Display;
+
+use crate::foo::Bar;",
+        &InsertUseConfig {
+            granularity: ImportGranularity::Item,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            skip_glob_imports: true,
+        },
+        ImportInsertStrategy::First,
+    );
+}
+
+#[test]
+fn insert_alphabetical_strategy() {
+    cov_mark::check!(insert_alphabetical);
+    check_with_strategy(
+        "std::fmt::Debug",
+        r"
+use external_crate::Baz;
+
+use std::io::Read;",
+        r"
+use external_crate::Baz;
+
+use std::fmt::Debug;
+use std::io::Read;",
+        &InsertUseConfig {
+            granularity: ImportGranularity::Item,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            skip_glob_imports: true,
+        },
+        ImportInsertStrategy::Alphabetical,
+    );
+}
+
+#[test]
+fn insert_alphabetical_strategy_unsorted() {
+    cov_mark::check!(insert_alphabetical);
+    check_with_strategy(
+        "core::fmt::Debug",
+        r"
+use std::io::Read;
+
+use core::mem;",
+        r"
+use std::io::Read;
+
+use core::fmt::Debug;
+use core::mem;",
+        &InsertUseConfig {
+            granularity: ImportGranularity::Item,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            skip_glob_imports: true,
+        },
+        ImportInsertStrategy::Alphabetical,
+    );
+}
+
+#[test]
+fn insert_alphabetical_strategy_last() {
+    cov_mark::check!(insert_alphabetical_last);
+    check_with_strategy(
+        "std::fmt::Debug",
+        r"
+use core::mem;
+
+use crate::foo::Bar;",
+        r"
+use core::mem;
+
+use crate::foo::Bar;
+use std::fmt::Debug;",
+        &InsertUseConfig {
+            granularity: ImportGranularity::Item,
+            enforce_granularity: true,
+            prefix_kind: PrefixKind::Plain,
+            group: true,
+            skip_glob_imports: true,
+        },
+        ImportInsertStrategy::Alphabetical,
+    );
+}
+
 #[test]
 fn insert_not_group() {
     cov_mark::check!(insert_no_grouping_last);
@@ -1038,6 +1138,22 @@ fn check_with_config(
     ra_fixture_before: &str,
     ra_fixture_after: &str,
     config: &InsertUseConfig,
+) {
+    check_with_strategy(
+        path,
+        ra_fixture_before,
+        ra_fixture_after,
+        config,
+        ImportInsertStrategy::GroupWithSimilar,
+    )
+}
+
+fn check_with_strategy(
+    path: &str,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+    config: &InsertUseConfig,
+    strategy: ImportInsertStrategy,
 ) {
     let (db, file_id, pos) = if ra_fixture_before.contains(CURSOR_MARKER) {
         let (db, file_id, range_or_offset) = RootDatabase::with_range_or_offset(ra_fixture_before);
@@ -1061,7 +1177,7 @@ fn check_with_config(
         .find_map(ast::Path::cast)
         .unwrap();
 
-    insert_use(&file, path, config);
+    insert_use_with_strategy(&file, path, config, strategy);
     let result = file.as_syntax_node().ancestors().last().unwrap().to_string();
     assert_eq_text!(&trim_indent(ra_fixture_after), &result);
 }
@@ -117,8 +117,8 @@ macro_rules! eprintln {
     Assist, AssistConfig, AssistId, AssistKind, AssistResolveStrategy, SingleResolve,
 };
 pub use ide_completion::{
-    CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
-    Snippet, SnippetScope,
+    CallParenCursor, CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind,
+    CompletionMatchingMode, CompletionRelevance, Snippet, SnippetScope,
 };
 pub use ide_db::{
     base_db::{
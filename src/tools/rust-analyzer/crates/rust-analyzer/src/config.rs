@@ -12,13 +12,13 @@
 use cfg::{CfgAtom, CfgDiff};
 use flycheck::FlycheckConfig;
 use ide::{
-    AssistConfig, CallableSnippets, CompletionConfig, DiagnosticsConfig, ExprFillDefaultMode,
-    HighlightConfig, HighlightRelatedConfig, HoverConfig, HoverDocFormat, InlayFieldsToResolve,
-    InlayHintsConfig, JoinLinesConfig, MemoryLayoutHoverConfig, MemoryLayoutHoverRenderKind,
-    Snippet, SnippetScope,
+    AssistConfig, CallParenCursor, CallableSnippets, CompletionConfig, CompletionMatchingMode,
+    DiagnosticsConfig, ExprFillDefaultMode, HighlightConfig, HighlightRelatedConfig, HoverConfig,
+    HoverDocFormat, InlayFieldsToResolve, InlayHintsConfig, JoinLinesConfig,
+    MemoryLayoutHoverConfig, MemoryLayoutHoverRenderKind, Snippet, SnippetScope,
 };
 use ide_db::{
-    imports::insert_use::{ImportGranularity, InsertUseConfig, PrefixKind},
+    imports::insert_use::{ImportGranularity, ImportInsertStrategy, InsertUseConfig, PrefixKind},
     SnippetCap,
 };
 use itertools::Itertools;
@@ -212,15 +212,32 @@ struct ConfigData {
         /// Toggles the additional completions that automatically add imports when completed.
         /// Note that your client must specify the `additionalTextEdits` LSP client capability to truly have this feature enabled.
         completion_autoimport_enable: bool       = "true",
+        /// Where to insert the `use` item of an auto-imported completion if it can't be merged
+        /// into an existing one.
+        completion_autoimport_insertStrategy: ImportInsertStrategyDef = "\"group_with_similar\"",
+        /// Maximum number of trait methods to offer with an automatic import after a `.`. If `None`, the limit is infinite.
+        completion_autoimport_maxResults: Option<usize> = "null",
         /// Toggles the additional completions that automatically show method calls and field accesses
         /// with `self` prefixed to them when inside a method.
         completion_autoself_enable: bool        = "true",
+        /// Where to place the cursor when completing a call of a function without arguments.
+        completion_callable_cursor: CallParenCursorDef = "\"after\"",
         /// Whether to add parenthesis and argument snippets when completing function.
         completion_callable_snippets: CallableCompletionDef  = "\"fill_arguments\"",
+        /// Whether to offer common groups of standard derives, like `Debug, Clone, PartialEq, Eq, Hash`, as single completions.
+        completion_deriveGroups_enable: bool = "false",
         /// Whether to show full function/method signatures in completion docs.
         completion_fullFunctionSignatures_enable: bool = "false",
+        /// Whether to offer the methods of traits next to an inherent `impl` that its type doesn't implement as inherent method stubs.
+        completion_inherentStubs_enable: bool = "false",
+        /// Whether to show keyword completions like `self::`, `crate::`, `if`, `match`, etc.
+        completion_keywords_enable: bool = "true",
         /// Maximum number of completions to return. If `None`, the limit is infinite.
         completion_limit: Option<usize> = "null",
+        /// How the names of completions have to match the identifier being typed.
+        completion_matchingMode: CompletionMatchingModeDef = "\"fuzzy\"",
+        /// Whether to mark stubs of trait methods that override a default implementation with an `// overriding default` comment.
+        completion_overrideComment_enable: bool = "false",
         /// Whether to show postfix snippets like `dbg`, `if`, `not`, etc.
         completion_postfix_enable: bool         = "true",
         /// Enables completions of private items and fields that are defined in the current workspace even if they are not visible at the current position.
@@ -1486,13 +1503,30 @@ pub fn completion(&self) -> CompletionConfig {
                 && completion_item_edit_resolve(&self.caps),
             enable_self_on_the_fly: self.data.completion_autoself_enable,
             enable_private_editable: self.data.completion_privateEditable_enable,
+            enable_keyword_completions: self.data.completion_keywords_enable,
+            enable_derive_groups: self.data.completion_deriveGroups_enable,
+            enable_inherent_stubs: self.data.completion_inherentStubs_enable,
             full_function_signatures: self.data.completion_fullFunctionSignatures_enable,
+            override_comment: self.data.completion_overrideComment_enable,
             callable: match self.data.completion_callable_snippets {
                 CallableCompletionDef::FillArguments => Some(CallableSnippets::FillArguments),
                 CallableCompletionDef::AddParentheses => Some(CallableSnippets::AddParentheses),
                 CallableCompletionDef::None => None,
             },
+            call_paren_cursor: match self.data.completion_callable_cursor {
+                CallParenCursorDef::Inside => CallParenCursor::Inside,
+                CallParenCursorDef::After => CallParenCursor::After,
+            },
+            matching_mode: match self.data.completion_matchingMode {
+                CompletionMatchingModeDef::Fuzzy => CompletionMatchingMode::Fuzzy,
+                CompletionMatchingModeDef::Prefix => CompletionMatchingMode::Prefix,
+            },
             insert_use: self.insert_use_config(),
+            import_insert_strategy: match self.data.completion_autoimport_insertStrategy {
+                ImportInsertStrategyDef::First => ImportInsertStrategy::First,
+                ImportInsertStrategyDef::GroupWithSimilar => ImportInsertStrategy::GroupWithSimilar,
+                ImportInsertStrategyDef::Alphabetical => ImportInsertStrategy::Alphabetical,
+            },
             prefer_no_std: self.data.imports_preferNoStd,
             prefer_prelude: self.data.imports_preferPrelude,
             snippet_cap: SnippetCap::new(try_or_def!(
@@ -1507,6 +1541,7 @@ pub fn completion(&self) -> CompletionConfig {
             )),
             snippets: self.snippets.clone(),
             limit: self.data.completion_limit,
+            flyimport_max_results: self.data.completion_autoimport_maxResults,
         }
     }
 
@@ -1909,6 +1944,28 @@ enum ImportGranularityDef {
     Module,
 }
 
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+enum ImportInsertStrategyDef {
+    First,
+    GroupWithSimilar,
+    Alphabetical,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+enum CallParenCursorDef {
+    Inside,
+    After,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+enum CompletionMatchingModeDef {
+    Fuzzy,
+    Prefix,
+}
+
 #[derive(Deserialize, Debug, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 enum CallableCompletionDef {
@@ -2409,6 +2466,41 @@ macro_rules! set {
                 { "type": "null" }
             ],
         },
+        "ImportInsertStrategyDef" => set! {
+            "type": "string",
+            "enum": [
+                "first",
+                "group_with_similar",
+                "alphabetical",
+            ],
+            "enumDescriptions": [
+                "Insert the `use` item before all other imports.",
+                "Insert the `use` item into the group of similar imports.",
+                "Insert the `use` item in sorted order, disregarding import groups."
+            ]
+        },
+        "CallParenCursorDef" => set! {
+            "type": "string",
+            "enum": [
+                "inside",
+                "after",
+            ],
+            "enumDescriptions": [
+                "Place the cursor inside the parentheses.",
+                "Place the cursor after the parentheses."
+            ]
+        },
+        "CompletionMatchingModeDef" => set! {
+            "type": "string",
+            "enum": [
+                "fuzzy",
+                "prefix",
+            ],
+            "enumDescriptions": [
+                "Offer all completions and leave the matching to the client.",
+                "Only offer completions whose name starts with the identifier being typed."
+            ]
+        },
         "CallableCompletionDef" => set! {
             "type": "string",
             "enum": [
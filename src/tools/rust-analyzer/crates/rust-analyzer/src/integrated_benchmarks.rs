@@ -10,9 +10,12 @@
 //! in release mode in VS Code. There's however "rust-analyzer: Copy Run Command Line"
 //! which you can use to paste the command in terminal and add `--release` manually.
 
-use ide::{CallableSnippets, Change, CompletionConfig, FilePosition, TextSize};
+use ide::{
+    CallParenCursor, CallableSnippets, Change, CompletionConfig, CompletionMatchingMode,
+    FilePosition, TextSize,
+};
 use ide_db::{
-    imports::insert_use::{ImportGranularity, InsertUseConfig},
+    imports::insert_use::{ImportGranularity, ImportInsertStrategy, InsertUseConfig},
     SnippetCap,
 };
 use project_model::CargoConfig;
@@ -131,8 +134,14 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_keyword_completions: true,
+            enable_derive_groups: false,
+            enable_inherent_stubs: false,
             full_function_signatures: false,
+            override_comment: false,
             callable: Some(CallableSnippets::FillArguments),
+            call_paren_cursor: CallParenCursor::After,
+            matching_mode: CompletionMatchingMode::Fuzzy,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -141,10 +150,12 @@ fn integrated_completion_benchmark() {
                 group: true,
                 skip_glob_imports: true,
             },
+            import_insert_strategy: ImportInsertStrategy::GroupWithSimilar,
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
             limit: None,
+            flyimport_max_results: None,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
@@ -175,8 +186,14 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_keyword_completions: true,
+            enable_derive_groups: false,
+            enable_inherent_stubs: false,
             full_function_signatures: false,
+            override_comment: false,
             callable: Some(CallableSnippets::FillArguments),
+            call_paren_cursor: CallParenCursor::After,
+            matching_mode: CompletionMatchingMode::Fuzzy,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -185,10 +202,12 @@ fn integrated_completion_benchmark() {
                 group: true,
                 skip_glob_imports: true,
             },
+            import_insert_strategy: ImportInsertStrategy::GroupWithSimilar,
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
             limit: None,
+            flyimport_max_results: None,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
@@ -216,8 +235,14 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_keyword_completions: true,
+            enable_derive_groups: false,
+            enable_inherent_stubs: false,
             full_function_signatures: false,
+            override_comment: false,
             callable: Some(CallableSnippets::FillArguments),
+            call_paren_cursor: CallParenCursor::After,
+            matching_mode: CompletionMatchingMode::Fuzzy,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -226,10 +251,12 @@ fn integrated_completion_benchmark() {
                 group: true,
                 skip_glob_imports: true,
             },
+            import_insert_strategy: ImportInsertStrategy::GroupWithSimilar,
             snippets: Vec::new(),
             prefer_no_std: false,
             prefer_prelude: true,
             limit: None,
+            flyimport_max_results: None,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
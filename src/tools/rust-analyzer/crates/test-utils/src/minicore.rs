@@ -17,6 +17,7 @@
 //!     cell: copy, drop
 //!     clone: sized
 //!     coerce_unsized: unsize
+//!     collect: iterator
 //!     concat:
 //!     copy: clone
 //!     default: sized
@@ -24,6 +25,7 @@
 //!     deref: sized
 //!     derive:
 //!     discriminant:
+//!     double_ended_iterator: iterator
 //!     drop:
 //!     eq: sized
 //!     error: fmt
@@ -1288,6 +1290,14 @@ fn filter_map<B, F>(self, _f: F) -> crate::iter::FilterMap<Self, F>
                     loop {}
                 }
                 // endregion:iterators
+                // region:collect
+                fn collect<B: crate::iter::FromIterator<Self::Item>>(self) -> B
+                where
+                    Self: Sized,
+                {
+                    loop {}
+                }
+                // endregion:collect
             }
             impl<I: Iterator + ?Sized> Iterator for &mut I {
                 type Item = I::Item;
@@ -1298,6 +1308,15 @@ fn next(&mut self) -> Option<I::Item> {
         }
         pub use self::iterator::Iterator;
 
+        // region:double_ended_iterator
+        mod double_ended {
+            pub trait DoubleEndedIterator: Iterator {
+                fn next_back(&mut self) -> Option<Self::Item>;
+            }
+        }
+        pub use self::double_ended::DoubleEndedIterator;
+        // endregion:double_ended_iterator
+
         mod collect {
             pub trait IntoIterator {
                 type Item;
@@ -1305,6 +1324,11 @@ pub trait IntoIterator {
                 #[lang = "into_iter"]
                 fn into_iter(self) -> Self::IntoIter;
             }
+            // region:collect
+            pub trait FromIterator<A> {
+                fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+            }
+            // endregion:collect
             impl<I: Iterator> IntoIterator for I {
                 type Item = I::Item;
                 type IntoIter = I;
@@ -1335,8 +1359,17 @@ fn next(&mut self) -> Option<T> {
             }
         }
         pub use self::collect::IntoIterator;
+        // region:collect
+        pub use self::collect::FromIterator;
+        // endregion:collect
     }
     pub use self::traits::{IntoIterator, Iterator};
+    // region:collect
+    pub use self::traits::FromIterator;
+    // endregion:collect
+    // region:double_ended_iterator
+    pub use self::traits::DoubleEndedIterator;
+    // endregion:double_ended_iterator
 }
 // endregion:iterator
 
@@ -1478,6 +1511,9 @@ impl $t {
                 pub const fn from_ne_bytes(bytes: [u8; mem::size_of::<Self>()]) -> Self {
                     unsafe { mem::transmute(bytes) }
                 }
+                pub const fn wrapping_add(self, rhs: Self) -> Self {
+                    loop {}
+                }
             }
         )*
     }